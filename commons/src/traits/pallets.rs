@@ -21,6 +21,30 @@ pub trait ActiveProposals<BlockNumber> {
 	fn max_proposals_limit() -> u32;
 }
 
+pub trait DaoCleanup<DaoId> {
+	/// Remove the state kept for a DAO that is being destroyed or dissolved.
+	///
+	/// Removes at most `max_items` storage items per call and returns `true` once nothing is left
+	/// to remove.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	/// - `max_items`: the maximum number of storage items to remove
+	fn cleanup_dao(dao_id: &DaoId, max_items: u32) -> bool;
+}
+
+impl<DaoId> DaoCleanup<DaoId> for () {
+	fn cleanup_dao(_dao_id: &DaoId, _max_items: u32) -> bool {
+		true
+	}
+}
+
+pub trait AssetDaos<AssetId> {
+	/// Get the ids of the DAOs using an asset as their token, the DAO that issued it first.
+	///
+	/// - `asset_id`: the unique identifier for the asset
+	fn dao_ids(asset_id: &AssetId) -> Vec<Vec<u8>>;
+}
+
 pub trait AssetInterface {
 	type AccountId;
 	type BlockNumber;
@@ -45,6 +69,13 @@ pub trait AssetInterface {
 		amount: Self::Balance,
 	) -> DispatchResult;
 
+	/// Burn
+	fn burn(
+		id: Self::AssetId,
+		target: &Self::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
 	/// Set metadata
 	fn set_metadata(
 		id: Self::AssetId,
@@ -54,9 +85,23 @@ pub trait AssetInterface {
 		decimals: u8,
 	) -> DispatchResult;
 
+	/// Get the decimals of an asset
+	fn get_decimals(id: Self::AssetId) -> u8;
+
+	/// Get the free balance of an account
+	fn balance(id: Self::AssetId, who: &Self::AccountId) -> Self::Balance;
+
 	/// Change the owner
 	fn change_owner(id: Self::AssetId, new_owner: Self::AccountId) -> DispatchResult;
 
+	/// Transfer
+	fn transfer(
+		id: Self::AssetId,
+		source: &Self::AccountId,
+		dest: &Self::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
 	/// Reserve
 	fn reserve(
 		id: Self::AssetId,
@@ -64,6 +109,25 @@ pub trait AssetInterface {
 		amount: Self::Balance,
 	) -> Result<Self::Balance, DispatchError>;
 
+	/// Unreserve
+	fn unreserve(
+		id: Self::AssetId,
+		target: impl Borrow<Self::AccountId>,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Start destroying an asset
+	fn start_destroy(id: Self::AssetId) -> DispatchResult;
+
+	/// Destroy up to `max_items` accounts of an asset that is being destroyed
+	fn destroy_accounts(id: Self::AssetId, max_items: u32) -> Result<u32, DispatchError>;
+
+	/// Destroy up to `max_items` approvals of an asset that is being destroyed
+	fn destroy_approvals(id: Self::AssetId, max_items: u32) -> Result<u32, DispatchError>;
+
+	/// Finish destroying an asset without accounts and approvals
+	fn finish_destroy(id: Self::AssetId) -> DispatchResult;
+
 	/// Get total historical supply
 	fn total_historical_supply(
 		id: Self::AssetId,
@@ -76,6 +140,13 @@ pub trait AssetInterface {
 		who: impl Borrow<Self::AccountId>,
 		block: Self::BlockNumber,
 	) -> Self::Balance;
+
+	/// Get the historical amount delegated to an account
+	fn total_historical_delegation(
+		id: Self::AssetId,
+		who: impl Borrow<Self::AccountId>,
+		block: Self::BlockNumber,
+	) -> Self::Balance;
 }
 
 pub trait UsableCheckpoints {
@@ -2,6 +2,8 @@
 
 #[ink::contract]
 mod genesis_dao {
+	use ink::prelude::vec::Vec;
+
 	#[ink(storage)]
 	pub struct GenesisDao {}
 
@@ -18,6 +20,40 @@ mod genesis_dao {
 		fn on_vote(&self, _voter: AccountId, voting_power: Balance) -> Balance {
 			voting_power
 		}
+
+		/// hook point for explaining `on_vote`
+		#[ink(message)]
+		fn explain_on_vote(
+			&self,
+			_voter: AccountId,
+			_voting_power: Balance,
+		) -> Vec<(AccountId, Balance)> {
+			Vec::new()
+		}
+
+		/// hook point for `on_proposal_create` pallet
+		#[ink(message)]
+		fn on_proposal_create(&self, _creator: AccountId, _payload: Vec<u8>) -> bool {
+			true
+		}
+
+		/// hook point for `on_proposal_metadata` pallet
+		#[ink(message)]
+		fn on_proposal_metadata(
+			&self,
+			_creator: AccountId,
+			_payload: Vec<u8>,
+			_meta: Vec<u8>,
+			_hash: Vec<u8>,
+		) -> bool {
+			true
+		}
+
+		/// hook point for `on_set_metadata` pallet
+		#[ink(message)]
+		fn on_set_metadata(&self, _meta: Vec<u8>, _hash: Vec<u8>) -> bool {
+			true
+		}
 	}
 
 	#[cfg(test)]
@@ -30,6 +66,29 @@ mod genesis_dao {
 			let genesis_dao = GenesisDao::new();
 			assert_eq!(genesis_dao.on_vote(AccountId::from([0x01; 32]), 0), 0);
 		}
+
+		#[ink::test]
+		fn test_on_proposal_create_hookpoint() {
+			let genesis_dao = GenesisDao::new();
+			assert!(genesis_dao.on_proposal_create(AccountId::from([0x01; 32]), Vec::new()));
+		}
+
+		#[ink::test]
+		fn test_on_proposal_metadata_hookpoint() {
+			let genesis_dao = GenesisDao::new();
+			assert!(genesis_dao.on_proposal_metadata(
+				AccountId::from([0x01; 32]),
+				Vec::new(),
+				b"https://my.cool.proposal".to_vec(),
+				Vec::new()
+			));
+		}
+
+		#[ink::test]
+		fn test_on_set_metadata_hookpoint() {
+			let genesis_dao = GenesisDao::new();
+			assert!(genesis_dao.on_set_metadata(b"https://my.cool.dao".to_vec(), Vec::new()));
+		}
 	}
 
 	#[cfg(all(test, feature = "e2e-tests"))]
@@ -2,6 +2,8 @@
 
 #[ink::contract]
 mod genesis_dao {
+	use ink::prelude::vec::Vec;
+
 	#[ink(storage)]
 	pub struct GenesisDao {}
 
@@ -19,6 +21,40 @@ mod genesis_dao {
 		fn on_vote(&self, _voter: AccountId, voting_power: Balance) -> Balance {
 			voting_power * 2
 		}
+
+		/// hook point for explaining `on_vote`
+		#[ink(message)]
+		fn explain_on_vote(
+			&self,
+			_voter: AccountId,
+			voting_power: Balance,
+		) -> Vec<(AccountId, Balance)> {
+			Vec::from([(self.env().account_id(), voting_power * 2)])
+		}
+
+		/// hook point for `on_proposal_create` pallet
+		#[ink(message)]
+		fn on_proposal_create(&self, _creator: AccountId, _payload: Vec<u8>) -> bool {
+			true
+		}
+
+		/// hook point for `on_proposal_metadata` pallet
+		#[ink(message)]
+		fn on_proposal_metadata(
+			&self,
+			_creator: AccountId,
+			_payload: Vec<u8>,
+			_meta: Vec<u8>,
+			_hash: Vec<u8>,
+		) -> bool {
+			true
+		}
+
+		/// hook point for `on_set_metadata` pallet
+		#[ink(message)]
+		fn on_set_metadata(&self, _meta: Vec<u8>, _hash: Vec<u8>) -> bool {
+			true
+		}
 	}
 
 	#[cfg(test)]
@@ -1,4 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
+use ink::prelude::vec::Vec;
 use ink_primitives::AccountId;
 
 type Balance = <ink::env::DefaultEnvironment as ink::env::Environment>::Balance;
@@ -8,4 +9,27 @@ pub trait GenesisDao {
 	/// hook point for `on_vote` pallet
 	#[ink(message)]
 	fn on_vote(&self, voter: AccountId, voting_power: Balance) -> Balance;
+
+	/// hook point for explaining `on_vote`: the voting power after each step
+	#[ink(message)]
+	fn explain_on_vote(&self, voter: AccountId, voting_power: Balance)
+		-> Vec<(AccountId, Balance)>;
+
+	/// hook point for `on_proposal_create` pallet
+	#[ink(message)]
+	fn on_proposal_create(&self, creator: AccountId, payload: Vec<u8>) -> bool;
+
+	/// hook point for `on_proposal_metadata` pallet
+	#[ink(message)]
+	fn on_proposal_metadata(
+		&self,
+		creator: AccountId,
+		payload: Vec<u8>,
+		meta: Vec<u8>,
+		hash: Vec<u8>,
+	) -> bool;
+
+	/// hook point for `on_set_metadata` pallet
+	#[ink(message)]
+	fn on_set_metadata(&self, meta: Vec<u8>, hash: Vec<u8>) -> bool;
 }
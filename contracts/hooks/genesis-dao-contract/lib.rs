@@ -154,6 +154,32 @@ mod genesis_dao {
 		pub fn get_vote_plugins(&self) -> Vec<AccountId> {
 			self.vote_plugins.clone()
 		}
+
+		/// Applies a single vote plugin to the voting power of a voter.
+		///
+		/// Falls back to the given voting power if the plugin cannot be called.
+		fn apply_vote_plugin(
+			&self,
+			plugin: AccountId,
+			voter: AccountId,
+			voting_power: Balance,
+		) -> Balance {
+			match build_call::<DefaultEnvironment>()
+				.call(plugin)
+				.exec_input(
+					ExecutionInput::new(Selector::new(ink::selector_bytes!(
+						"Vote::get_voting_power"
+					)))
+					.push_arg(&voter)
+					.push_arg(&voting_power),
+				)
+				.returns::<Balance>()
+				.try_invoke()
+			{
+				Ok(new_voting_power) => new_voting_power.unwrap_or(voting_power),
+				Err(_) => voting_power,
+			}
+		}
 	}
 
 	impl genesis_dao_contract_trait::GenesisDao for GenesisDao {
@@ -172,26 +198,100 @@ mod genesis_dao {
 		/// - `Balance`: Updated voting power after considering all vote plugins.
 		#[ink(message)]
 		fn on_vote(&self, voter: AccountId, voting_power: Balance) -> Balance {
+			self.vote_plugins.iter().fold(voting_power, |voting_power, plugin| {
+				self.apply_vote_plugin(*plugin, voter, voting_power)
+			})
+		}
+
+		/// `explain_on_vote` Hook Point
+		///
+		/// This function explains `on_vote`: it applies the registered vote plugins in the
+		/// same order and records the voting power after each of them.
+		///
+		/// # Arguments
+		///
+		/// - `voter`: AccountId of the voter.
+		/// - `voting_power`: Initial voting power of the voter.
+		///
+		/// # Returns
+		///
+		/// - `Vec<(AccountId, Balance)>`: Each vote plugin with the voting power after it.
+		#[ink(message)]
+		fn explain_on_vote(
+			&self,
+			voter: AccountId,
+			voting_power: Balance,
+		) -> Vec<(AccountId, Balance)> {
 			let mut voting_power = voting_power;
+			let mut steps = Vec::new();
 
-			for contract_id in self.vote_plugins.iter() {
-				voting_power = match build_call::<DefaultEnvironment>()
-					.call(*contract_id)
-					.exec_input(
-						ExecutionInput::new(Selector::new(ink::selector_bytes!(
-							"Vote::get_voting_power"
-						)))
-						.push_arg(&voter)
-						.push_arg(&voting_power),
-					)
-					.returns::<Balance>()
-					.try_invoke()
-				{
-					Ok(new_voting_power) => new_voting_power.unwrap_or(voting_power),
-					Err(_) => voting_power,
-				};
+			for plugin in self.vote_plugins.iter() {
+				voting_power = self.apply_vote_plugin(*plugin, voter, voting_power);
+				steps.push((*plugin, voting_power));
 			}
-			voting_power
+			steps
+		}
+
+		/// `on_proposal_create` Hook Point
+		///
+		/// This function gets called when a proposal is created. The registry accepts all
+		/// proposals, more restrictive DAOs can validate the payload here.
+		///
+		/// # Arguments
+		///
+		/// - `creator`: AccountId of the proposal creator.
+		/// - `payload`: SCALE encoded kind of the proposal.
+		///
+		/// # Returns
+		///
+		/// - `bool`: Whether the proposal is accepted.
+		#[ink(message)]
+		fn on_proposal_create(&self, _creator: AccountId, _payload: Vec<u8>) -> bool {
+			true
+		}
+
+		/// `on_proposal_metadata` Hook Point
+		///
+		/// This function gets called when the metadata of a proposal is set. The registry
+		/// accepts all proposals, more restrictive DAOs can validate the metadata here.
+		///
+		/// # Arguments
+		///
+		/// - `creator`: AccountId of the proposal creator.
+		/// - `payload`: SCALE encoded kind of the proposal.
+		/// - `meta`: The metadata URL of the proposal.
+		/// - `hash`: The hash of the metadata.
+		///
+		/// # Returns
+		///
+		/// - `bool`: Whether the proposal is accepted.
+		#[ink(message)]
+		fn on_proposal_metadata(
+			&self,
+			_creator: AccountId,
+			_payload: Vec<u8>,
+			_meta: Vec<u8>,
+			_hash: Vec<u8>,
+		) -> bool {
+			true
+		}
+
+		/// `on_set_metadata` Hook Point
+		///
+		/// This function gets called when the metadata of the DAO is set. The registry accepts
+		/// all metadata that passed the built-in checks of the pallet.
+		///
+		/// # Arguments
+		///
+		/// - `meta`: The metadata URL.
+		/// - `hash`: The hash of the metadata.
+		///
+		/// # Returns
+		///
+		/// - `bool`: Whether the metadata is accepted.
+		#[ink(message)]
+		fn on_set_metadata(&self, _meta: Vec<u8>, _hash: Vec<u8>) -> bool {
+			true
 		}
 	}
 }
@@ -8,50 +8,77 @@
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
+use sc_client_api::{Backend, BlockchainEvents};
+use sc_rpc::SubscriptionTaskExecutor;
 use sc_transaction_pool_api::TransactionPool;
-use solochain_runtime::{opaque::Block, AccountId, Balance, Index};
+use solochain_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Index, Runtime};
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
 pub use sc_rpc_api::DenyUnsafe;
 
+pub mod dao;
+pub mod events;
+
 /// Full client dependencies.
-pub struct FullDeps<C, P> {
+pub struct FullDeps<C, P, B> {
 	/// The client instance to use.
 	pub client: Arc<C>,
 	/// Transaction pool instance.
 	pub pool: Arc<P>,
+	/// The backend instance to use.
+	pub backend: Arc<B>,
+	/// Executor for subscriptions.
+	pub subscription_executor: SubscriptionTaskExecutor,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
 }
 
 /// Instantiate all full RPC extensions.
-pub fn create_full<C, P>(
-	deps: FullDeps<C, P>,
+pub fn create_full<C, P, B>(
+	deps: FullDeps<C, P, B>,
 ) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
 where
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+	C: BlockchainEvents<Block>,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_dao_core_runtime_api::DaoCoreApi<
+		Block,
+		AccountId,
+		pallet_dao_core::AssetIdOf<Runtime>,
+		pallet_dao_core::DaoOf<Runtime>,
+	>,
+	C::Api: pallet_dao_votes_runtime_api::DaoApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		dao::ProposalId,
+		pallet_dao_core::DaoOf<Runtime>,
+		pallet_dao_votes::GovernanceOf<Runtime>,
+		pallet_dao_votes::ProposalOf<Runtime>,
+		pallet_dao_votes::VotingPowerExplanationOf<Runtime>,
+	>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
+	B: Backend<Block> + Send + Sync + 'static,
 {
+	use dao::{Dao, DaoApiServer};
+	use events::{DaoEvents, DaoEventsApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcModule::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps { client, pool, backend, subscription_executor, deny_unsafe } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
-
-	// Extend this RPC with a custom API by using the following syntax.
-	// `YourRpcStruct` should have a reference to a client, which is needed
-	// to call into the runtime.
-	// `module.merge(YourRpcTrait::into_rpc(YourRpcStruct::new(ReferenceToClient, ...)))?;`
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Dao::new(client.clone()).into_rpc())?;
+	module.merge(DaoEvents::new(client, backend, subscription_executor).into_rpc())?;
 
 	Ok(module)
 }
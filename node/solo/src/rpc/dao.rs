@@ -0,0 +1,450 @@
+//! RPC methods for querying DAOs, their proposals and token history.
+//!
+//! The methods are thin wrappers around the `DaoApi` and `DaoCoreApi` runtime APIs that turn the
+//! SCALE encoded runtime types into plain JSON: identifiers, names and metadata are returned as
+//! strings and balances as `NumberOrHex`, as they may exceed the integer range of JSON numbers.
+//!
+//! Unless a block hash is given, the methods query the state of the last finalized block.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_dao_core::{AssetIdOf, DaoOf};
+use pallet_dao_core_runtime_api::DaoCoreApi as DaoCoreRuntimeApi;
+use pallet_dao_votes::{
+	GovernanceOf, ProposalOf, ProposalStatus, Voting, VotingPowerExplanationOf,
+};
+use pallet_dao_votes_runtime_api::DaoApi as DaoRuntimeApi;
+use serde::{Deserialize, Serialize};
+use solochain_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Runtime};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+/// The maximum number of DAOs returned by a page of the DAO listings.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// The identifier of a proposal.
+pub type ProposalId = <Runtime as pallet_dao_votes::Config>::ProposalId;
+
+/// A DAO together with its governance.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DaoInfo {
+	/// The identifier of the DAO.
+	pub id: String,
+	/// The owner of the DAO.
+	pub owner: AccountId,
+	/// The name of the DAO.
+	pub name: String,
+	/// The DAO token, if one has been issued.
+	pub asset_id: Option<u32>,
+	/// The address of the DAO metadata.
+	pub meta: String,
+	/// The hash of the DAO metadata.
+	pub meta_hash: Bytes,
+	/// The cap on the supply of the DAO token, if any.
+	pub max_supply: Option<NumberOrHex>,
+	/// The identifier of the parent DAO, if this is a sub-DAO.
+	pub parent: Option<String>,
+	/// The governance of the DAO, if one has been set.
+	pub governance: Option<GovernanceInfo>,
+}
+
+/// The governance of a DAO.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GovernanceInfo {
+	/// The number of blocks a proposal is open for voting.
+	pub proposal_duration: u32,
+	/// The token deposit required to create a proposal.
+	pub proposal_token_deposit: NumberOrHex,
+	/// The rules for accepting proposals.
+	pub voting: VotingInfo,
+}
+
+/// The rules for accepting the proposals of a DAO.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VotingInfo {
+	/// Proposals need a majority of ayes.
+	#[serde(rename_all = "camelCase")]
+	Majority {
+		/// The required majority in 1024ths of the token supply.
+		minimum_majority_per_1024: u8,
+	},
+	/// Proposals pass unless objected to.
+	#[serde(rename_all = "camelCase")]
+	Optimistic {
+		/// The nays required to reject a proposal in 1024ths of the token supply.
+		objection_threshold_per_1024: u16,
+	},
+	/// Proposals are evaluated by the DAO hooks.
+	Custom,
+}
+
+/// A proposal with its current tally.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalInfo {
+	/// The identifier of the proposal.
+	pub id: ProposalId,
+	/// The identifier of the DAO of the proposal.
+	pub dao_id: String,
+	/// The creator of the proposal.
+	pub creator: AccountId,
+	/// The block of the token balances the proposal is voted with.
+	pub birth_block: BlockNumber,
+	/// The address of the proposal metadata.
+	pub meta: String,
+	/// The hash of the proposal metadata.
+	pub meta_hash: Bytes,
+	/// The status of the proposal.
+	pub status: String,
+	/// The voting power in favor of the proposal.
+	pub in_favor: NumberOrHex,
+	/// The voting power against the proposal.
+	pub against: NumberOrHex,
+}
+
+fn string_of(bytes: &[u8]) -> String {
+	String::from_utf8_lossy(bytes).into_owned()
+}
+
+impl From<GovernanceOf<Runtime>> for GovernanceInfo {
+	fn from(governance: GovernanceOf<Runtime>) -> Self {
+		let voting = match governance.voting {
+			Voting::Majority { minimum_majority_per_1024 } =>
+				VotingInfo::Majority { minimum_majority_per_1024 },
+			Voting::Optimistic { objection_threshold_per_1024 } =>
+				VotingInfo::Optimistic { objection_threshold_per_1024 },
+			Voting::Custom => VotingInfo::Custom,
+		};
+		Self {
+			proposal_duration: governance.proposal_duration,
+			proposal_token_deposit: governance.proposal_token_deposit.into(),
+			voting,
+		}
+	}
+}
+
+impl From<(DaoOf<Runtime>, Option<GovernanceOf<Runtime>>)> for DaoInfo {
+	fn from((dao, governance): (DaoOf<Runtime>, Option<GovernanceOf<Runtime>>)) -> Self {
+		Self {
+			id: string_of(&dao.id),
+			owner: dao.owner,
+			name: string_of(&dao.name),
+			asset_id: dao.asset_id,
+			meta: string_of(&dao.meta),
+			meta_hash: dao.meta_hash.into_inner().into(),
+			max_supply: dao.max_supply.map(Into::into),
+			parent: dao.parent.map(|parent| string_of(&parent)),
+			governance: governance.map(Into::into),
+		}
+	}
+}
+
+impl From<(ProposalId, ProposalOf<Runtime>)> for ProposalInfo {
+	fn from((id, proposal): (ProposalId, ProposalOf<Runtime>)) -> Self {
+		let status = match proposal.status {
+			ProposalStatus::Running => "Running",
+			ProposalStatus::Accepted => "Accepted",
+			ProposalStatus::Rejected => "Rejected",
+			ProposalStatus::Faulty => "Faulty",
+			ProposalStatus::Implemented => "Implemented",
+		};
+		Self {
+			id,
+			dao_id: string_of(&proposal.dao_id),
+			creator: proposal.creator,
+			birth_block: proposal.birth_block,
+			meta: string_of(&proposal.meta),
+			meta_hash: proposal.meta_hash.into_inner().into(),
+			status: status.into(),
+			in_favor: proposal.in_favor.into(),
+			against: proposal.against.into(),
+		}
+	}
+}
+
+/// DAO RPC methods.
+#[rpc(server)]
+pub trait DaoApi<BlockHash> {
+	/// A DAO together with its governance.
+	#[method(name = "dao_getDao")]
+	fn get_dao(&self, dao_id: String, at: Option<BlockHash>) -> RpcResult<Option<DaoInfo>>;
+
+	/// A page of all DAOs, starting after the DAO `start` and holding at most `limit` DAOs
+	/// (capped at `MAX_PAGE_SIZE`).
+	#[method(name = "dao_listDaos")]
+	fn list_daos(
+		&self,
+		start: Option<String>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DaoInfo>>;
+
+	/// A page of the DAOs of `owner`, paged like `dao_listDaos`.
+	#[method(name = "dao_listDaosByOwner")]
+	fn list_daos_by_owner(
+		&self,
+		owner: AccountId,
+		start: Option<String>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DaoInfo>>;
+
+	/// The running proposals of a DAO with their current tallies.
+	#[method(name = "dao_listProposals")]
+	fn list_proposals(&self, dao_id: String, at: Option<BlockHash>)
+		-> RpcResult<Vec<ProposalInfo>>;
+
+	/// The voting power `voter` would vote with on a proposal, `None` if they cannot vote.
+	#[method(name = "dao_votingPower")]
+	fn voting_power(
+		&self,
+		proposal_id: ProposalId,
+		voter: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+
+	/// Whether a proposal would be accepted if it was finalized at the given block.
+	#[method(name = "dao_dryRunFinalize")]
+	fn dry_run_finalize(&self, proposal_id: ProposalId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+	/// The token balance of `who` in a DAO at `block`.
+	#[method(name = "dao_historicalBalance")]
+	fn historical_balance(
+		&self,
+		dao_id: String,
+		who: AccountId,
+		block: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+
+	/// The token supply of a DAO at `block`.
+	#[method(name = "dao_historicalSupply")]
+	fn historical_supply(
+		&self,
+		dao_id: String,
+		block: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+}
+
+/// Error codes of the DAO RPC methods.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+	/// The runtime rejected the request.
+	DispatchError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DispatchError => 2,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message.to_string(),
+		Some(format!("{:?}", e)),
+	))
+	.into()
+}
+
+/// Provides the DAO RPC methods.
+pub struct Dao<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Dao<C, B> {
+	/// Create new `Dao` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C> Dao<C, Block>
+where
+	C: ProvideRuntimeApi<Block>,
+	C::Api: DaoRuntimeApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		ProposalId,
+		DaoOf<Runtime>,
+		GovernanceOf<Runtime>,
+		ProposalOf<Runtime>,
+		VotingPowerExplanationOf<Runtime>,
+	>,
+{
+	/// Add the governance to a page of DAOs.
+	fn with_governance(
+		&self,
+		daos: Vec<DaoOf<Runtime>>,
+		at: <Block as BlockT>::Hash,
+	) -> RpcResult<Vec<DaoInfo>> {
+		let api = self.client.runtime_api();
+		daos.into_iter()
+			.map(|dao| {
+				let governance = DaoRuntimeApi::get_dao(&*api, at, dao.id.to_vec())
+					.map_err(|e| runtime_error("Unable to query the governance.", e))?
+					.and_then(|(_, governance)| governance);
+				Ok((dao, governance).into())
+			})
+			.collect()
+	}
+}
+
+impl<C> DaoApiServer<<Block as BlockT>::Hash> for Dao<C, Block>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: DaoRuntimeApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		ProposalId,
+		DaoOf<Runtime>,
+		GovernanceOf<Runtime>,
+		ProposalOf<Runtime>,
+		VotingPowerExplanationOf<Runtime>,
+	>,
+	C::Api: DaoCoreRuntimeApi<Block, AccountId, AssetIdOf<Runtime>, DaoOf<Runtime>>,
+{
+	fn get_dao(
+		&self,
+		dao_id: String,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<DaoInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().finalized_hash);
+		let dao = DaoRuntimeApi::get_dao(&*self.client.runtime_api(), at, dao_id.into_bytes())
+			.map_err(|e| runtime_error("Unable to query the DAO.", e))?;
+		Ok(dao.map(Into::into))
+	}
+
+	fn list_daos(
+		&self,
+		start: Option<String>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DaoInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().finalized_hash);
+		let daos = self
+			.client
+			.runtime_api()
+			.get_daos(at, start.map(String::into_bytes), limit.min(MAX_PAGE_SIZE))
+			.map_err(|e| runtime_error("Unable to query the DAOs.", e))?;
+		self.with_governance(daos, at)
+	}
+
+	fn list_daos_by_owner(
+		&self,
+		owner: AccountId,
+		start: Option<String>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DaoInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().finalized_hash);
+		let daos = self
+			.client
+			.runtime_api()
+			.get_daos_by_owner(at, owner, start.map(String::into_bytes), limit.min(MAX_PAGE_SIZE))
+			.map_err(|e| runtime_error("Unable to query the DAOs.", e))?;
+		self.with_governance(daos, at)
+	}
+
+	fn list_proposals(
+		&self,
+		dao_id: String,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ProposalInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().finalized_hash);
+		let proposals = self
+			.client
+			.runtime_api()
+			.get_active_proposals(at, dao_id.into_bytes())
+			.map_err(|e| runtime_error("Unable to query the proposals.", e))?;
+		Ok(proposals.into_iter().map(Into::into).collect())
+	}
+
+	fn voting_power(
+		&self,
+		proposal_id: ProposalId,
+		voter: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().finalized_hash);
+		let power = self
+			.client
+			.runtime_api()
+			.get_voting_power(at, proposal_id, voter)
+			.map_err(|e| runtime_error("Unable to query the voting power.", e))?;
+		Ok(power.map(Into::into))
+	}
+
+	fn dry_run_finalize(
+		&self,
+		proposal_id: ProposalId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let at = at.unwrap_or_else(|| self.client.info().finalized_hash);
+		self.client
+			.runtime_api()
+			.dry_run_finalize(at, proposal_id)
+			.map_err(|e| runtime_error("Unable to dry-run the finalization.", e))?
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::DispatchError.into(),
+					"The proposal cannot be finalized.",
+					Some(format!("{:?}", e)),
+				))
+				.into()
+			})
+	}
+
+	fn historical_balance(
+		&self,
+		dao_id: String,
+		who: AccountId,
+		block: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().finalized_hash);
+		let balance = self
+			.client
+			.runtime_api()
+			.get_historical_balance(at, dao_id.into_bytes(), who, block)
+			.map_err(|e| runtime_error("Unable to query the historical balance.", e))?;
+		Ok(balance.map(Into::into))
+	}
+
+	fn historical_supply(
+		&self,
+		dao_id: String,
+		block: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().finalized_hash);
+		let supply = self
+			.client
+			.runtime_api()
+			.get_historical_supply(at, dao_id.into_bytes(), block)
+			.map_err(|e| runtime_error("Unable to query the historical supply.", e))?;
+		Ok(supply.map(Into::into))
+	}
+}
@@ -0,0 +1,383 @@
+//! RPC subscription to the events of a DAO.
+//!
+//! The events are read from `System::Events` of each block in the local database and filtered by
+//! the DAO they concern: dao-core and dao-votes events name their DAO or a proposal, dao-assets
+//! events name an asset, which concerns the DAO that issued it and the DAOs it is attached to.
+//! Only finalized blocks are followed, so no event is ever retracted. Catching up on past blocks
+//! needs their state, so it is limited by the state pruning of the node and to
+//! `MAX_CATCH_UP_BLOCKS`.
+
+use std::sync::Arc;
+
+use codec::{Decode, Encode};
+use futures::{future::ready, stream, FutureExt, StreamExt};
+use jsonrpsee::{
+	core::Error as JsonRpseeError, proc_macros::rpc, types::SubscriptionResult, SubscriptionSink,
+};
+use pallet_dao_core::{AssetIdOf, DaoOf};
+use pallet_dao_core_runtime_api::DaoCoreApi;
+use pallet_dao_votes::{GovernanceOf, ProposalOf, VotingPowerExplanationOf};
+use pallet_dao_votes_runtime_api::DaoApi as DaoRuntimeApi;
+use sc_client_api::{Backend, BlockchainEvents, StateBackend};
+use sc_rpc::SubscriptionTaskExecutor;
+use scale_info::{PortableRegistry, Registry};
+use scale_value::Value;
+use serde::Serialize;
+use solochain_runtime::{
+	opaque::Block, AccountId, Balance, BlockNumber, Hash, Runtime, RuntimeEvent,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::hashing::twox_128;
+use sp_runtime::traits::Header as HeaderT;
+
+use super::dao::ProposalId;
+
+type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+/// The maximum number of past blocks a subscription can catch up on, a day of 6 second blocks.
+pub const MAX_CATCH_UP_BLOCKS: BlockNumber = 14_400;
+
+/// An event of a DAO.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DaoEvent {
+	/// The number of the block the event was emitted in.
+	pub block_number: BlockNumber,
+	/// The hash of the block the event was emitted in.
+	pub block_hash: Hash,
+	/// The index of the event among the events of the block.
+	pub event_index: u32,
+	/// The decoded event, the pallet variant wrapping the event variant with its fields.
+	pub event: Value,
+}
+
+/// DAO event RPC methods.
+#[rpc(server)]
+pub trait DaoEventsApi {
+	/// Subscribe to the events of a DAO in newly finalized blocks.
+	///
+	/// With `from_block` the subscription first catches up on the events since that block, which
+	/// has to be finalized and at most `MAX_CATCH_UP_BLOCKS` behind the last finalized block.
+	/// The subscription ends with an error if the events of a block cannot be read or decoded.
+	#[subscription(
+		name = "dao_subscribeEvents" => "dao_event",
+		unsubscribe = "dao_unsubscribeEvents",
+		item = DaoEvent
+	)]
+	fn subscribe_events(&self, dao_id: String, from_block: Option<BlockNumber>);
+}
+
+/// What an event is about.
+enum Subject {
+	Dao(Vec<u8>),
+	Proposal(ProposalId),
+	Asset(AssetIdOf<Runtime>),
+}
+
+/// The subjects of an event, empty if the event does not concern a DAO.
+///
+/// The events of the DAO pallets are matched exhaustively, so that a new event has to be assigned
+/// its subjects - or explicitly none - before the node compiles.
+fn subjects(event: &RuntimeEvent) -> Vec<Subject> {
+	use pallet_dao_assets::Event as AssetsEvent;
+	use pallet_dao_core::Event as CoreEvent;
+	use pallet_dao_votes::Event as VotesEvent;
+
+	match event {
+		RuntimeEvent::DaoCore(event) => match event {
+			CoreEvent::SubDaoCreated { dao_id, parent } =>
+				vec![Subject::Dao(dao_id.to_vec()), Subject::Dao(parent.to_vec())],
+			CoreEvent::DaoCreated { dao_id, .. } |
+			CoreEvent::DaoDestroyed { dao_id } |
+			CoreEvent::DaoTokenIssued { dao_id, .. } |
+			CoreEvent::DaoMetadataSet { dao_id } |
+			CoreEvent::DaoOwnerChanged { dao_id, .. } |
+			CoreEvent::DaoOwnershipProposed { dao_id, .. } |
+			CoreEvent::DaoOwnershipTransferCancelled { dao_id } |
+			CoreEvent::DaoDissolutionStarted { dao_id } |
+			CoreEvent::VestedTokensClaimed { dao_id, .. } |
+			CoreEvent::DaoTokenMinted { dao_id, .. } |
+			CoreEvent::DaoTokenBurned { dao_id, .. } |
+			CoreEvent::DaoMaxSupplySet { dao_id, .. } |
+			CoreEvent::DaoTokenMetadataUpdated { dao_id, .. } |
+			CoreEvent::DaoRenamed { dao_id, .. } |
+			CoreEvent::DaoTokenOffered { dao_id, .. } |
+			CoreEvent::DaoTokenAttached { dao_id, .. } |
+			CoreEvent::MembershipEnabled { dao_id } |
+			CoreEvent::MemberAdded { dao_id, .. } |
+			CoreEvent::MemberRemoved { dao_id, .. } |
+			CoreEvent::DaoOwnerForceChanged { dao_id, .. } |
+			CoreEvent::DaoMetadataForceSet { dao_id } |
+			CoreEvent::DaoForceDestroyed { dao_id } => vec![Subject::Dao(dao_id.to_vec())],
+			// a committed DAO id is secret until the DAO is created
+			CoreEvent::DaoIdCommitted { .. } |
+			CoreEvent::DaoIdCommitmentCancelled { .. } |
+			CoreEvent::__Ignore(..) => vec![],
+		},
+		RuntimeEvent::Votes(event) => match event {
+			VotesEvent::ProposalCreated { dao_id, .. } |
+			VotesEvent::SetCommitReveal { dao_id, .. } |
+			VotesEvent::SetGovernanceMajorityVote { dao_id, .. } |
+			VotesEvent::SetGovernanceOptimisticVote { dao_id, .. } |
+			VotesEvent::SubDaoGovernanceChanged { dao_id, .. } =>
+				vec![Subject::Dao(dao_id.to_vec())],
+			VotesEvent::ProposalMetadataSet { proposal_id } |
+			VotesEvent::ProposalSlotExpired { proposal_id } |
+			VotesEvent::ProposalFaulted { proposal_id, .. } |
+			VotesEvent::ProposalAccepted { proposal_id } |
+			VotesEvent::ProposalRejected { proposal_id } |
+			VotesEvent::ProposalCounting { proposal_id } |
+			VotesEvent::ProposalImplemented { proposal_id } |
+			VotesEvent::VoteCast { proposal_id, .. } |
+			VotesEvent::VoteCommitted { proposal_id, .. } |
+			VotesEvent::VoteCommitmentRemoved { proposal_id, .. } |
+			VotesEvent::MembershipChangeFailed { proposal_id, .. } =>
+				vec![Subject::Proposal(*proposal_id)],
+			VotesEvent::__Ignore(..) => vec![],
+		},
+		RuntimeEvent::Assets(event) => match event {
+			AssetsEvent::Created { asset_id, .. } |
+			AssetsEvent::Issued { asset_id, .. } |
+			AssetsEvent::Transferred { asset_id, .. } |
+			AssetsEvent::Burned { asset_id, .. } |
+			AssetsEvent::AccountsDestroyed { asset_id, .. } |
+			AssetsEvent::ApprovalsDestroyed { asset_id, .. } |
+			AssetsEvent::DestructionStarted { asset_id } |
+			AssetsEvent::Destroyed { asset_id } |
+			AssetsEvent::ForceCreated { asset_id, .. } |
+			AssetsEvent::MetadataSet { asset_id, .. } |
+			AssetsEvent::MetadataCleared { asset_id } |
+			AssetsEvent::ApprovedTransfer { asset_id, .. } |
+			AssetsEvent::ApprovalCancelled { asset_id, .. } |
+			AssetsEvent::TransferredApproved { asset_id, .. } |
+			AssetsEvent::AssetStatusChanged { asset_id } |
+			AssetsEvent::Delegated { asset_id, .. } |
+			AssetsEvent::DelegationRevoked { asset_id, .. } => vec![Subject::Asset(*asset_id)],
+			AssetsEvent::__Ignore(..) => vec![],
+		},
+		_ => vec![],
+	}
+}
+
+/// Decodes runtime events into self-describing values.
+struct EventDecoder {
+	types: PortableRegistry,
+	event_type: u32,
+}
+
+impl EventDecoder {
+	fn new() -> Self {
+		let mut registry = Registry::new();
+		let event_type = registry.register_type(&scale_info::meta_type::<RuntimeEvent>()).id;
+		Self { types: registry.into(), event_type }
+	}
+
+	fn decode(&self, event: &RuntimeEvent) -> Result<Value, String> {
+		let encoded = event.encode();
+		scale_value::scale::decode_as_type(&mut &encoded[..], self.event_type, &self.types)
+			.map(Value::remove_context)
+			.map_err(|e| format!("Failed to decode an event: {:?}", e))
+	}
+}
+
+/// Provides the DAO event subscription.
+pub struct DaoEvents<C, B> {
+	client: Arc<C>,
+	backend: Arc<B>,
+	executor: SubscriptionTaskExecutor,
+	decoder: Arc<EventDecoder>,
+}
+
+impl<C, B> DaoEvents<C, B> {
+	/// Create new `DaoEvents` with the given references to the client and the backend.
+	pub fn new(client: Arc<C>, backend: Arc<B>, executor: SubscriptionTaskExecutor) -> Self {
+		Self { client, backend, executor, decoder: Arc::new(EventDecoder::new()) }
+	}
+}
+
+impl<C, B> DaoEvents<C, B>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: DaoCoreApi<Block, AccountId, AssetIdOf<Runtime>, DaoOf<Runtime>>,
+	C::Api: DaoRuntimeApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		ProposalId,
+		DaoOf<Runtime>,
+		GovernanceOf<Runtime>,
+		ProposalOf<Runtime>,
+		VotingPowerExplanationOf<Runtime>,
+	>,
+	B: Backend<Block> + Send + Sync + 'static,
+{
+	/// The events emitted in a block.
+	///
+	/// Fails if the state of the block is not available or its events do not decode, e.g. for
+	/// blocks of a runtime the node does not know.
+	fn block_events(backend: &B, hash: Hash) -> Result<Vec<EventRecord>, String> {
+		let key = [twox_128(b"System"), twox_128(b"Events")].concat();
+		let events = backend
+			.state_at(hash)
+			.map_err(|e| format!("The state of block {} is not available: {}", hash, e))?
+			.storage(&key)
+			.map_err(|e| format!("Failed to read the events of block {}: {}", hash, e))?;
+		match events {
+			Some(events) => Decode::decode(&mut &events[..])
+				.map_err(|e| format!("Failed to decode the events of block {}: {}", hash, e)),
+			None => Ok(Vec::new()),
+		}
+	}
+
+	/// The ids of the DAOs a subject belongs to.
+	///
+	/// Proposals and assets are looked up in the state after the block and, if they have been
+	/// removed in the block, in the state before it.
+	fn dao_ids(client: &C, hash: Hash, parent_hash: Hash, subject: Subject) -> Vec<Vec<u8>> {
+		match subject {
+			Subject::Dao(dao_id) => vec![dao_id],
+			Subject::Proposal(proposal_id) => [hash, parent_hash]
+				.into_iter()
+				.find_map(|at| {
+					client.runtime_api().get_proposal_dao(at, proposal_id).ok().flatten()
+				})
+				.into_iter()
+				.collect(),
+			Subject::Asset(asset_id) => [hash, parent_hash]
+				.into_iter()
+				.map(|at| client.runtime_api().get_asset_daos(at, asset_id).unwrap_or_default())
+				.find(|dao_ids| !dao_ids.is_empty())
+				.unwrap_or_default(),
+		}
+	}
+
+	/// The events of the DAO `dao_id` emitted in a block.
+	fn dao_events(
+		client: &C,
+		backend: &B,
+		decoder: &EventDecoder,
+		dao_id: &[u8],
+		number: BlockNumber,
+		hash: Hash,
+	) -> Result<Vec<DaoEvent>, String> {
+		let parent_hash =
+			client.header(hash).ok().flatten().map_or(hash, |header| *header.parent_hash());
+
+		Self::block_events(backend, hash)?
+			.into_iter()
+			.enumerate()
+			.filter(|(_, record)| {
+				subjects(&record.event).into_iter().any(|subject| {
+					Self::dao_ids(client, hash, parent_hash, subject).iter().any(|id| id == dao_id)
+				})
+			})
+			.map(|(index, record)| {
+				decoder.decode(&record.event).map(|event| DaoEvent {
+					block_number: number,
+					block_hash: hash,
+					event_index: index as u32,
+					event,
+				})
+			})
+			.collect()
+	}
+}
+
+impl<C, B> DaoEventsApiServer for DaoEvents<C, B>
+where
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: DaoCoreApi<Block, AccountId, AssetIdOf<Runtime>, DaoOf<Runtime>>,
+	C::Api: DaoRuntimeApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		ProposalId,
+		DaoOf<Runtime>,
+		GovernanceOf<Runtime>,
+		ProposalOf<Runtime>,
+		VotingPowerExplanationOf<Runtime>,
+	>,
+	B: Backend<Block> + Send + Sync + 'static,
+{
+	fn subscribe_events(
+		&self,
+		mut sink: SubscriptionSink,
+		dao_id: String,
+		from_block: Option<BlockNumber>,
+	) -> SubscriptionResult {
+		// listen before looking at the finalized block, so that no block is missed in between
+		let finality = self.client.finality_notification_stream();
+		let finalized = self.client.info().finalized_number;
+
+		if let Some(from) = from_block {
+			let error = if from > finalized {
+				Some(format!("Block {} is not finalized yet.", from))
+			} else if finalized - from >= MAX_CATCH_UP_BLOCKS {
+				Some(format!(
+					"Block {} is more than {} blocks behind the finalized block.",
+					from, MAX_CATCH_UP_BLOCKS
+				))
+			} else if !self
+				.client
+				.hash(from)
+				.ok()
+				.flatten()
+				.map_or(false, |hash| self.backend.state_at(hash).is_ok())
+			{
+				Some(format!("The state of block {} is not available to catch up from.", from))
+			} else {
+				None
+			};
+			if let Some(error) = error {
+				let _ = sink.reject(JsonRpseeError::Custom(error));
+				return Ok(())
+			}
+		}
+
+		let from = from_block.unwrap_or_else(|| finalized.saturating_add(1));
+		let client = self.client.clone();
+		let catch_up = stream::iter(from..=finalized).filter_map(move |number| {
+			ready(client.hash(number).ok().flatten().map(|hash| (number, hash)))
+		});
+		// a notification finalizes the blocks of its tree route implicitly
+		let client = self.client.clone();
+		let live = finality
+			.flat_map(|notification| {
+				let hashes = notification.tree_route.iter().copied().chain([notification.hash]);
+				stream::iter(hashes.collect::<Vec<_>>())
+			})
+			.filter_map(move |hash| {
+				ready(client.number(hash).ok().flatten().map(|number| (number, hash)))
+			})
+			.filter(move |(number, _)| ready(*number > finalized));
+
+		let (client, backend, decoder) =
+			(self.client.clone(), self.backend.clone(), self.decoder.clone());
+		let dao_id = dao_id.into_bytes();
+		let events = catch_up
+			.chain(live)
+			.flat_map(move |(number, hash)| {
+				let events =
+					match Self::dao_events(&client, &backend, &decoder, &dao_id, number, hash) {
+						Ok(events) => events.into_iter().map(Ok).collect::<Vec<_>>(),
+						Err(e) => vec![Err(e)],
+					};
+				stream::iter(events)
+			})
+			.boxed();
+
+		let fut = async move {
+			sink.pipe_from_try_stream(events).await;
+		};
+		self.executor.spawn("dao-events-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+}
@@ -0,0 +1,39 @@
+//! Runtime API for querying the token history of the dao-assets pallet.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// Queries of historical balances, supplies and delegations, e.g. to show the voting power
+	/// of an account at the birth block of a proposal.
+	pub trait AssetsHistoryApi<AssetId, AccountId, Balance, BlockNumber>
+	where
+		AssetId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// The balance of `who` at `block` including delegations to them, together with the
+		/// block of the checkpoint it was read from.
+		fn total_historical_balance(
+			asset_id: AssetId,
+			who: AccountId,
+			block: BlockNumber,
+		) -> (BlockNumber, Balance);
+
+		/// The supply of an asset at `block`, `None` if the block is beyond the history horizon.
+		fn total_historical_supply(asset_id: AssetId, block: BlockNumber) -> Option<Balance>;
+
+		/// The blocks of all checkpoints of `who`, in ascending order.
+		fn get_checkpoint_blocks(asset_id: AssetId, who: AccountId) -> Vec<BlockNumber>;
+
+		/// The amount delegated to `who` at `block`, together with the delegators and their
+		/// delegated amounts.
+		fn get_delegations(
+			asset_id: AssetId,
+			who: AccountId,
+			block: BlockNumber,
+		) -> (Balance, Vec<(AccountId, Balance)>);
+	}
+}
@@ -1,16 +1,29 @@
 //! Functions for the Assets pallet.
 
 use super::*;
-use commons::traits::pallets::{ActiveProposals, UsableCheckpoints};
+use commons::traits::pallets::{ActiveProposals, AssetDaos, UsableCheckpoints};
 use frame_support::{traits::Get, BoundedVec};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_std::{borrow::Borrow, fmt::Debug};
 
 // The main implementation block for the module.
 impl<T: Config> Pallet<T> {
-	/// Get DaoId
-	pub fn dao_id(asset_id: &T::AssetId) -> Vec<u8> {
-		Metadata::<T>::get(asset_id).symbol.to_vec()
+	/// Get the ids of all DAOs using the asset, the issuing DAO and those that attached it
+	pub fn dao_ids(asset_id: &T::AssetId) -> Vec<Vec<u8>> {
+		T::AssetDaos::dao_ids(asset_id)
+	}
+
+	/// Get the starting time of the active proposals of all DAOs using the asset
+	fn active_proposals_starting_time(
+		asset_id: &T::AssetId,
+		current_block: BlockNumberFor<T>,
+	) -> Vec<BlockNumberFor<T>> {
+		Self::dao_ids(asset_id)
+			.into_iter()
+			.flat_map(|dao_id| {
+				T::ActiveProposals::active_proposals_starting_time(dao_id, current_block)
+			})
+			.collect()
 	}
 
 	/// Get the asset `id` free balance of `who`, or zero if the asset-account doesn't exist.
@@ -88,10 +101,9 @@ impl<T: Config> Pallet<T> {
 		balance: T::Balance,
 	) {
 		let current_block = frame_system::Pallet::<T>::block_number();
-		let dao_id = Self::dao_id(&asset_id);
 
 		// get all proposals
-		let proposal_start_dates = <T::ActiveProposals as ActiveProposals::<BlockNumberFor<T>>>::active_proposals_starting_time(dao_id, current_block);
+		let proposal_start_dates = Self::active_proposals_starting_time(&asset_id, current_block);
 		// get all checkpoints
 		let (mut checkpoint_blocks, (_last_chp_block, last_chp)) =
 			Self::get_checkpoint_blocks(&asset_id, who.borrow());
@@ -155,6 +167,20 @@ impl<T: Config> Pallet<T> {
 		latest
 	}
 
+	/// Get the amount delegated to an account `who` at a certain `block`, together with the
+	/// delegators and their delegated amounts.
+	pub fn historical_delegations(
+		asset_id: T::AssetId,
+		who: impl Borrow<T::AccountId>,
+		block: BlockNumberFor<T>,
+	) -> (AssetBalanceOf<T>, Vec<(T::AccountId, AssetBalanceOf<T>)>) {
+		AccountHistory::<T>::iter_prefix((asset_id, who.borrow()))
+			.filter(|(bl_num, _)| bl_num <= &block)
+			.max_by_key(|(bl_num, _)| *bl_num)
+			.map(|(_, chp)| (*chp.delegated_amount(), chp.delegated.into_iter().collect()))
+			.unwrap_or_else(|| (Zero::zero(), Vec::new()))
+	}
+
 	/// Search a history for the value at a specific block.
 	/// Result may be None, if the age of the requested block is at or beyond
 	/// the HistoryHorizon and history has been removed.
@@ -397,7 +423,6 @@ impl<T: Config> Pallet<T> {
 
 			Ok(())
 		})?;
-		Self::mutate_account(id, beneficiary, amount);
 		Self::deposit_event(Event::Issued {
 			asset_id: id,
 			owner: beneficiary.clone(),
@@ -445,7 +470,6 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Will return an error and do nothing or will decrease the amount and return the amount
 	/// reduced by.
-	#[cfg(test)]
 	pub(super) fn do_burn(
 		id: T::AssetId,
 		target: &T::AccountId,
@@ -502,6 +526,7 @@ impl<T: Config> Pallet<T> {
 				// account already removed by take
 				Self::dead_account(id, target, details);
 				debug_assert!(account.balance.is_zero(), "checked in prep; qed");
+				Self::update_account_history(id, target, Zero::zero());
 				return Ok(())
 			};
 			Self::update_account_history(id, target, account.balance + account.reserved);
@@ -962,9 +987,7 @@ impl<T: Config> Pallet<T> {
 		AccountHistory::<T>::insert((asset_id, source), current_block, src_chp);
 		AccountHistory::<T>::insert((asset_id, target), current_block, tg_chp);
 
-		let dao_id = Self::dao_id(asset_id);
-		let proposal_start_dates =
-			T::ActiveProposals::active_proposals_starting_time(dao_id, current_block);
+		let proposal_start_dates = Self::active_proposals_starting_time(asset_id, current_block);
 		Self::remove_unused_checkpoint(
 			&asset_id,
 			&proposal_start_dates,
@@ -1001,6 +1024,15 @@ impl<T: Config> commons::traits::pallets::AssetInterface for Pallet<T> {
 		Pallet::<T>::do_mint(id, beneficiary, amount)
 	}
 
+	fn burn(
+		id: Self::AssetId,
+		target: &Self::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		let f = DebitFlags { keep_alive: false, best_effort: false };
+		Pallet::<T>::do_burn(id, target, amount, f)
+	}
+
 	fn force_create(
 		id: Self::AssetId,
 		owner: Self::AccountId,
@@ -1019,10 +1051,28 @@ impl<T: Config> commons::traits::pallets::AssetInterface for Pallet<T> {
 		Pallet::<T>::do_set_metadata(id, from, name, symbol, decimals)
 	}
 
+	fn get_decimals(id: Self::AssetId) -> u8 {
+		Metadata::<T>::get(id).decimals
+	}
+
+	fn balance(id: Self::AssetId, who: &Self::AccountId) -> Self::Balance {
+		Pallet::<T>::balance(id, who)
+	}
+
 	fn change_owner(id: Self::AssetId, new_owner: Self::AccountId) -> DispatchResult {
 		Pallet::<T>::change_owner(id, new_owner)
 	}
 
+	fn transfer(
+		id: Self::AssetId,
+		source: &Self::AccountId,
+		dest: &Self::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+		Pallet::<T>::do_transfer(id, source, dest, amount, f)
+	}
+
 	fn reserve(
 		id: Self::AssetId,
 		target: impl Borrow<Self::AccountId>,
@@ -1031,6 +1081,30 @@ impl<T: Config> commons::traits::pallets::AssetInterface for Pallet<T> {
 		Pallet::<T>::do_reserve(id, target, amount)
 	}
 
+	fn unreserve(
+		id: Self::AssetId,
+		target: impl Borrow<Self::AccountId>,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Pallet::<T>::do_unreserve(id, target, amount)
+	}
+
+	fn start_destroy(id: Self::AssetId) -> DispatchResult {
+		Pallet::<T>::do_start_destroy(id, None)
+	}
+
+	fn destroy_accounts(id: Self::AssetId, max_items: u32) -> Result<u32, DispatchError> {
+		Pallet::<T>::do_destroy_accounts(id, max_items)
+	}
+
+	fn destroy_approvals(id: Self::AssetId, max_items: u32) -> Result<u32, DispatchError> {
+		Pallet::<T>::do_destroy_approvals(id, max_items)
+	}
+
+	fn finish_destroy(id: Self::AssetId) -> DispatchResult {
+		Pallet::<T>::do_finish_destroy(id)
+	}
+
 	fn total_historical_supply(
 		id: Self::AssetId,
 		block: Self::BlockNumber,
@@ -1045,6 +1119,14 @@ impl<T: Config> commons::traits::pallets::AssetInterface for Pallet<T> {
 	) -> Self::Balance {
 		Pallet::<T>::total_historical_balance(id, who, block).1
 	}
+
+	fn total_historical_delegation(
+		id: Self::AssetId,
+		who: impl Borrow<Self::AccountId>,
+		block: Self::BlockNumber,
+	) -> Self::Balance {
+		Pallet::<T>::historical_delegations(id, who, block).0
+	}
 }
 
 impl<T: Config> UsableCheckpoints for Pallet<T> {
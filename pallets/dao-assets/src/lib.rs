@@ -17,6 +17,7 @@ pub mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
 pub mod weights;
 
 mod functions;
@@ -25,7 +26,7 @@ mod types;
 
 pub use types::*;
 
-use commons::traits::pallets::ActiveProposals;
+use commons::traits::pallets::{ActiveProposals, AssetDaos};
 
 use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
@@ -84,6 +85,9 @@ pub mod pallet {
 
 		type ActiveProposals: ActiveProposals<<HeaderFor<Self> as Header>::Number>;
 
+		/// The DAOs using an asset as their token, e.g. `pallet_dao_core`.
+		type AssetDaos: AssetDaos<Self::AssetId>;
+
 		/// The units in which we record balances.
 		type Balance: Member
 			+ Parameter
@@ -0,0 +1,39 @@
+//! Storage migrations for the dao-assets pallet.
+use super::*;
+
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+	use sp_std::marker::PhantomData;
+
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+
+	/// Migrate dao-assets from storage version 0 to 1.
+	///
+	/// Chains that added the pallet before it declared a storage version report version 0, the
+	/// layout of version 1 is the same, so only the version is set. Later migrations can then
+	/// rely on the on-chain version.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "storage version not updated");
+			Ok(())
+		}
+	}
+}
@@ -4,8 +4,9 @@ use super::*;
 use crate as pallet_dao_assets;
 
 use frame_support::{
-	construct_runtime,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
+	construct_runtime, parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64},
+	PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::{ConstU8, H256};
@@ -73,6 +74,10 @@ impl pallet_balances::Config for Test {
 	type MaxHolds = ();
 }
 
+parameter_types! {
+	pub const DaoCorePalletId: PalletId = PalletId(*b"gdao/dao");
+}
+
 impl pallet_dao_core::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MinLength = ConstU32<3>;
@@ -81,14 +86,30 @@ impl pallet_dao_core::Config for Test {
 	type MaxLengthMetadata = ConstU32<256>;
 	type Currency = Balances;
 	type DaoDeposit = ConstU64<10>;
+	type DaoDepositPerByte = ConstU64<0>;
 	type TokenUnits = ConstU8<10>;
+	type MaxTokenUnits = ConstU8<18>;
+	type MaxMetadataHistory = ConstU32<3>;
+	type MaxAttachedDaos = ConstU32<3>;
+	type MaxMembers = ConstU32<10>;
+	type RequireDaoIdCommitment = ConstBool<false>;
+	type DaoIdCommitmentDelay = ConstU32<2>;
+	type DaoIdCommitmentExpiry = ConstU32<100>;
 	type AssetId = u32;
 	type ExposeAsset = Assets;
 	type CoreWeightInfo = ();
+	type MetadataValidator = ();
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type OwnershipTransferExpiry = ConstU32<100>;
+	type DaoCleanup = ();
+	type DissolveItemsLimit = ConstU32<5>;
+	type PalletId = DaoCorePalletId;
+	type MaxAllocations = ConstU32<10>;
 }
 
 impl Config for Test {
 	type ActiveProposals = ActiveProposalsMock;
+	type AssetDaos = AssetDaosMock;
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u64;
 	type AssetIdParameter = u32;
@@ -118,6 +139,13 @@ impl ActiveProposals<BlockNumberFor<Test>> for ActiveProposalsMock {
 	}
 }
 
+pub struct AssetDaosMock;
+impl AssetDaos<u32> for AssetDaosMock {
+	fn dao_ids(_asset_id: &u32) -> Vec<Vec<u8>> {
+		vec![b"DAO".to_vec()]
+	}
+}
+
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
@@ -721,4 +721,37 @@ fn historical_balance_and_delegation_works() {
 		assert_eq!(Assets::total_historical_balance(asset_id, ALICE, 40), (40, 50_u64));
 		assert_eq!(Assets::total_historical_balance(asset_id, BOB, 40), (40, 350_u64));
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn historical_delegations_works() {
+	new_test_ext().execute_with(|| {
+		let asset_id = 999;
+		run_to_block(10);
+		assert_eq!(Assets::historical_delegations(asset_id, BOB, 10), (0, vec![]));
+
+		run_to_block(20);
+		assert_ok!(Assets::delegate(RuntimeOrigin::signed(ALICE), asset_id, BOB));
+		assert_ok!(Assets::delegate(RuntimeOrigin::signed(CHARLIE), asset_id, BOB));
+		assert_eq!(
+			Assets::historical_delegations(asset_id, BOB, 20),
+			(600, vec![(ALICE, 100), (CHARLIE, 500)])
+		);
+
+		run_to_block(30);
+		assert_ok!(Assets::revoke_delegation(RuntimeOrigin::signed(ALICE), asset_id, BOB));
+		assert_eq!(Assets::historical_delegations(asset_id, BOB, 30), (500, vec![(CHARLIE, 500)]));
+		assert_eq!(Assets::historical_delegations(asset_id, ALICE, 30), (0, vec![]));
+	});
+}
+
+#[test]
+fn migrates_to_v1() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Assets>();
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Assets::on_chain_storage_version(), 1);
+	});
+}
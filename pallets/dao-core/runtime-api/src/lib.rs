@@ -0,0 +1,31 @@
+//! Runtime API for querying the DAOs of the dao-core pallet.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// Paged queries of DAOs.
+	///
+	/// DAOs are returned in storage order, a page starts after the DAO id given as `start` -
+	/// usually the id of the last DAO of the previous page.
+	pub trait DaoCoreApi<AccountId, AssetId, Dao>
+	where
+		AccountId: Codec,
+		AssetId: Codec,
+		Dao: Codec,
+	{
+		/// A DAO by its id.
+		fn get_dao(dao_id: Vec<u8>) -> Option<Dao>;
+
+		/// A page of all DAOs.
+		fn get_daos(start: Option<Vec<u8>>, limit: u32) -> Vec<Dao>;
+
+		/// A page of the DAOs of an owner.
+		fn get_daos_by_owner(owner: AccountId, start: Option<Vec<u8>>, limit: u32) -> Vec<Dao>;
+
+		/// The ids of the DAOs using an asset: the DAO that issued it and the DAOs it is attached
+		/// to.
+		fn get_asset_daos(asset_id: AssetId) -> Vec<Vec<u8>>;
+	}
+}
@@ -10,7 +10,9 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use commons::traits::pallets::AssetInterface;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
 use frame_system::RawOrigin;
 
 use crate::Pallet as DaoCore;
@@ -91,15 +93,308 @@ benchmarks! {
 		assert_last_event::<T>(Event::DaoMetadataSet { dao_id }.into());
 	}
 
-	change_owner {
+	force_change_owner {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller).into(), dao_id.clone(), 1000u32.into())?;
+		let new_owner: T::AccountId = account("new owner", 0, 0);
+	}: _(RawOrigin::Root, dao_id.clone(), new_owner.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoOwnerForceChanged { dao_id, new_owner }.into());
+	}
+
+	propose_owner {
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao::<T>(caller.clone());
 		let new_owner: T::AccountId = account("new owner", 0, 0);
 	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), new_owner.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoOwnershipProposed { dao_id, new_owner }.into());
+	}
+
+	accept_ownership {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let new_owner: T::AccountId = account("new owner", 0, 0);
+		DaoCore::<T>::propose_owner(
+			RawOrigin::Signed(caller).into(),
+			dao_id.clone(),
+			new_owner.clone()
+		)?;
+	}: _(RawOrigin::Signed(new_owner.clone()), dao_id.clone())
 	verify {
 		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
 		assert_last_event::<T>(Event::DaoOwnerChanged { dao_id, new_owner }.into());
 	}
 
+	cancel_ownership_transfer {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let new_owner: T::AccountId = account("new owner", 0, 0);
+		DaoCore::<T>::propose_owner(
+			RawOrigin::Signed(caller.clone()).into(),
+			dao_id.clone(),
+			new_owner
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoOwnershipTransferCancelled { dao_id }.into());
+	}
+
+	dissolve_dao {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoDissolutionStarted { dao_id }.into());
+	}
+
+	dissolve_dao_step {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+		let asset_id = DaoCore::<T>::load_dao(dao_id.clone()).unwrap().asset_id.unwrap();
+		// the worst case is a step destroying the maximum number of token accounts
+		for i in 0..T::DissolveItemsLimit::get() {
+			let holder: T::AccountId = account("holder", i, 0);
+			T::ExposeAsset::mint(asset_id, &holder, 10u32.into())?;
+		}
+		DaoCore::<T>::dissolve_dao(RawOrigin::Signed(caller).into(), dao_id.clone())?;
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+	}: {
+		DaoCore::<T>::dissolve_dao_step(dao_id)?;
+	}
+	verify {
+		assert_eq!(T::ExposeAsset::get_asset(asset_id).unwrap().accounts, 1);
+	}
+
+	issue_token_with_distribution {
+		let n in 0 .. T::MaxAllocations::get();
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let supply: BalanceOf<T> = 1_000_000u32.into();
+		let allocations: Vec<AllocationOf<T>> = (0..n)
+			.map(|i| Allocation {
+				beneficiary: account("beneficiary", i, 0),
+				amount: 100u32.into(),
+				vesting: Some(VestingSchedule { start: 1u32.into(), duration: 100 }),
+			})
+			.collect();
+		let allocations: BoundedVec<_, _> = allocations.try_into().expect("fits");
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), supply, allocations, RemainderDestination::Owner)
+	verify {
+		let asset_id = DaoCore::<T>::load_dao(dao_id.clone()).unwrap().asset_id.unwrap();
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoTokenIssued { dao_id, supply, asset_id }.into());
+	}
+
+	claim_vested {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let amount: BalanceOf<T> = 100u32.into();
+		let allocations: BoundedVec<_, _> = sp_std::vec![Allocation {
+			beneficiary: beneficiary.clone(),
+			amount,
+			vesting: Some(VestingSchedule { start: 0u32.into(), duration: 0 }),
+		}].try_into().expect("fits");
+		DaoCore::<T>::issue_token_with_distribution(
+			RawOrigin::Signed(caller).into(),
+			dao_id.clone(),
+			1000u32.into(),
+			allocations,
+			RemainderDestination::Owner
+		)?;
+	}: _(RawOrigin::Signed(beneficiary.clone()), dao_id.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::VestedTokensClaimed { dao_id, who: beneficiary, amount }.into());
+	}
+
+	mint {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let amount: BalanceOf<T> = 100u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), beneficiary.clone(), amount)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoTokenMinted { dao_id, beneficiary, amount }.into());
+	}
+
+	burn {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token_with_distribution(
+			RawOrigin::Signed(caller.clone()).into(),
+			dao_id.clone(),
+			1000u32.into(),
+			Default::default(),
+			RemainderDestination::DaoAccount
+		)?;
+		let amount: BalanceOf<T> = 100u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), amount)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoTokenBurned { dao_id, amount }.into());
+	}
+
+	set_max_supply {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+		let max_supply: Option<BalanceOf<T>> = Some(2000u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), max_supply)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoMaxSupplySet { dao_id, max_supply }.into());
+	}
+
+	issue_token_with_parameters {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let supply: BalanceOf<T> = 1000u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), supply, b"GDT".to_vec(), 12, 10u32.into())
+	verify {
+		let asset_id = DaoCore::<T>::load_dao(dao_id.clone()).unwrap().asset_id.unwrap();
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoTokenIssued { dao_id, supply, asset_id }.into());
+	}
+
+	update_token_metadata {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+		let asset_id = DaoCore::<T>::load_dao(dao_id.clone()).unwrap().asset_id.unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), b"GDT".to_vec())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoTokenMetadataUpdated { dao_id, asset_id }.into());
+	}
+
+	rename_dao {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), b"Genesis DAO 2".to_vec())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		let name: BoundedVec<_, _> = b"Genesis DAO 2".to_vec().try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoRenamed { dao_id, name }.into());
+	}
+
+	offer_token {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+		let asset_id = DaoCore::<T>::load_dao(dao_id).unwrap().asset_id.unwrap();
+		let spin_off = b"SDAO".to_vec();
+		DaoCore::<T>::create_dao(RawOrigin::Signed(caller.clone()).into(), spin_off.clone(), b"Spin-off DAO".to_vec())?;
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, spin_off.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = spin_off.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoTokenOffered { dao_id, asset_id }.into());
+	}
+
+	attach_token {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller.clone()).into(), dao_id.clone(), 1000u32.into())?;
+		let asset_id = DaoCore::<T>::load_dao(dao_id).unwrap().asset_id.unwrap();
+		let spin_off = b"SDAO".to_vec();
+		DaoCore::<T>::create_dao(RawOrigin::Signed(caller.clone()).into(), spin_off.clone(), b"Spin-off DAO".to_vec())?;
+		DaoCore::<T>::offer_token(RawOrigin::Signed(caller.clone()).into(), asset_id, spin_off.clone())?;
+	}: _(RawOrigin::Signed(caller.clone()), spin_off.clone(), asset_id)
+	verify {
+		let dao_id: BoundedVec<_, _> = spin_off.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoTokenAttached { dao_id, asset_id }.into());
+	}
+
+	create_sub_dao {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let child = b"SDAO".to_vec();
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), child.clone(), b"Sub DAO".to_vec())
+	verify {
+		let parent: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		let dao_id: BoundedVec<_, _> = child.try_into().expect("fits");
+		assert_last_event::<T>(Event::SubDaoCreated { dao_id, parent }.into());
+	}
+
+	enable_membership {
+		let n in 1 .. T::MaxMembers::get();
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let members: Vec<T::AccountId> = (0..n).map(|i| account("member", i, 0)).collect();
+		let members: BoundedVec<_, _> = members.try_into().expect("fits");
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), members)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::MembershipEnabled { dao_id }.into());
+	}
+
+	commit_dao_id {
+		let caller = setup_caller::<T>();
+		let commitment = DaoCore::<T>::dao_id_commitment(b"GDAO", b"salt", &caller);
+	}: _(RawOrigin::Signed(caller.clone()), commitment)
+	verify {
+		assert_last_event::<T>(Event::DaoIdCommitted { commitment, who: caller }.into());
+	}
+
+	create_dao_with_commitment {
+		let caller = setup_caller::<T>();
+		let parent_id = setup_dao::<T>(caller.clone());
+		let child = b"SDAO".to_vec();
+		let commitment = DaoCore::<T>::dao_id_commitment(&child, b"salt", &caller);
+		DaoCore::<T>::commit_dao_id(RawOrigin::Signed(caller.clone()).into(), commitment)?;
+		frame_system::Pallet::<T>::set_block_number(T::DaoIdCommitmentDelay::get().into());
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		child.clone(),
+		b"Sub DAO".to_vec(),
+		b"salt".to_vec(),
+		Some(parent_id.clone())
+	)
+	verify {
+		let parent: BoundedVec<_, _> = parent_id.try_into().expect("fits");
+		let dao_id: BoundedVec<_, _> = child.try_into().expect("fits");
+		assert_last_event::<T>(Event::SubDaoCreated { dao_id, parent }.into());
+	}
+
+	cancel_dao_id_commitment {
+		let caller = setup_caller::<T>();
+		let commitment = DaoCore::<T>::dao_id_commitment(b"GDAO", b"salt", &caller);
+		DaoCore::<T>::commit_dao_id(RawOrigin::Signed(caller.clone()).into(), commitment)?;
+	}: _(RawOrigin::Signed(caller.clone()), commitment)
+	verify {
+		assert_last_event::<T>(Event::DaoIdCommitmentCancelled { commitment }.into());
+	}
+
+	force_set_metadata {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller);
+		let metadata = b"http://my.cool.dao".to_vec();
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+	}: _(RawOrigin::Root, dao_id.clone(), metadata, hash)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoMetadataForceSet { dao_id }.into());
+	}
+
+	force_destroy_dao {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		DaoCore::<T>::issue_token(RawOrigin::Signed(caller).into(), dao_id.clone(), 1000u32.into())?;
+	}: _(RawOrigin::Root, dao_id.clone())
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::DaoForceDestroyed { dao_id }.into());
+	}
+
 	impl_benchmark_test_suite!(DaoCore, crate::mock::new_test_ext(), crate::mock::Test)
 }
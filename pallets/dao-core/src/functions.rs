@@ -1,6 +1,24 @@
 //! Functions for the dao-core pallet.
 use super::*;
-use crate::types::{DaoOf, MetadataOf};
+use crate::types::{
+	AllocationOf, DaoOf, MetadataOf, MetadataRecordOf, RemainderDestination, TokenParameters,
+	Vesting, VestingOf,
+};
+use commons::{
+	traits::pallets::{AssetDaos, AssetInterface, DaoCleanup},
+	types::assets::AssetStatus,
+};
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	sp_runtime::{
+		traits::{AccountIdConversion, CheckedAdd, Hash},
+		Perbill, SaturatedConversion,
+	},
+	storage::StoragePrefixedMap,
+	traits::{ConstU32, Get, ReservableCurrency},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
 
 impl<T: Config> Pallet<T> {
 	/// Load a dao from storage by id.
@@ -9,13 +27,746 @@ impl<T: Config> Pallet<T> {
 	pub fn load_dao(dao_id: Vec<u8>) -> Result<DaoOf<T>, Error<T>> {
 		let bounded_dao_id: BoundedVec<_, _> =
 			dao_id.try_into().map_err(|_| Error::<T>::DaoIdInvalidLengthTooLong)?;
+		ensure!(!<Dissolving<T>>::contains_key(&bounded_dao_id), Error::<T>::DaoIsDissolving);
 		<Daos<T>>::get(bounded_dao_id).ok_or(Error::<T>::DaoDoesNotExist)
 	}
 
+	/// The account of a DAO, holding e.g. the vesting tokens of its initial distribution.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn dao_account_id(dao_id: &DaoIdOf<T>) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(dao_id)
+	}
+
+	/// Check that `who` controls the DAO, being either its owner or its account.
+	///
+	/// - `dao`: the DAO to check
+	/// - `who`: the account to check
+	pub fn ensure_dao_controller(dao: &DaoOf<T>, who: &T::AccountId) -> Result<(), Error<T>> {
+		ensure!(
+			dao.owner == *who || Self::dao_account_id(&dao.id) == *who,
+			Error::<T>::DaoSignerNotOwner
+		);
+		Ok(())
+	}
+
+	/// The token parameters used by default: the DAO id as symbol, `TokenUnits` decimals and a
+	/// minimum balance of one.
+	///
+	/// - `dao`: the DAO to issue the token for
+	pub fn default_token_parameters(dao: &DaoOf<T>) -> TokenParameters<BalanceOf<T>> {
+		TokenParameters {
+			symbol: dao.id.clone().into(),
+			decimals: <T as Config>::TokenUnits::get(),
+			min_balance: One::one(),
+		}
+	}
+
+	/// Check that a token symbol follows the rules of DAO ids.
+	///
+	/// - `symbol`: the symbol to be validated
+	pub fn symbol_is_valid(symbol: &[u8]) -> Result<(), Error<T>> {
+		ensure!(
+			symbol.len() >= T::MinLength::get() as usize,
+			Error::<T>::TokenSymbolInvalidLengthTooShort
+		);
+		ensure!(
+			symbol.len() <= T::MaxLengthId::get() as usize,
+			Error::<T>::TokenSymbolInvalidLengthTooLong
+		);
+		ensure!(
+			symbol.iter().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()),
+			Error::<T>::TokenSymbolInvalidChar
+		);
+		Ok(())
+	}
+
+	/// Issue the token of a DAO and distribute its `supply`.
+	///
+	/// Allocations with a vesting schedule are transferred to the DAO account until they vest, the
+	/// supply that has not been allocated is minted to the `remainder` destination.
+	///
+	/// - `dao`: the DAO to issue the token for
+	/// - `supply`: the total supply of the token
+	/// - `parameters`: the symbol, decimals and minimum balance of the token
+	/// - `allocations`: the accounts receiving parts of the supply
+	/// - `remainder`: the recipient of the supply that has not been allocated
+	pub fn do_issue_token(
+		dao: DaoOf<T>,
+		supply: BalanceOf<T>,
+		parameters: TokenParameters<BalanceOf<T>>,
+		allocations: Vec<AllocationOf<T>>,
+		remainder: RemainderDestination,
+	) -> Result<AssetIdOf<T>, DispatchError> {
+		ensure!(dao.asset_id.is_none(), Error::<T>::DaoTokenAlreadyIssued);
+		ensure!(!Self::is_membership_dao(&dao.id), Error::<T>::DaoUsesMembership);
+		Self::symbol_is_valid(&parameters.symbol)?;
+		ensure!(
+			parameters.decimals <= <T as Config>::MaxTokenUnits::get(),
+			Error::<T>::TokenUnitsTooHigh
+		);
+		ensure!(!parameters.min_balance.is_zero(), Error::<T>::TokenMinBalanceZero);
+		if let Some(max_supply) = dao.max_supply {
+			ensure!(supply <= max_supply, Error::<T>::MaxSupplyExceeded);
+		}
+		let allocated = allocations
+			.iter()
+			.try_fold(BalanceOf::<T>::zero(), |total, allocation| {
+				total.checked_add(&allocation.amount)
+			})
+			.filter(|allocated| *allocated <= supply)
+			.ok_or(Error::<T>::AllocationsExceedSupply)?;
+
+		// create a fresh asset
+		<CurrentAssetId<T>>::mutate(|asset_id| asset_id.saturating_inc());
+		let asset_id = <CurrentAssetId<T>>::get();
+		T::ExposeAsset::force_create(asset_id, dao.owner.clone(), parameters.min_balance)?;
+		<AssetToDao<T>>::insert(asset_id, &dao.id);
+
+		// distribute it to the allocations, vesting tokens are held by the DAO account
+		let dao_account = Self::dao_account_id(&dao.id);
+		for allocation in allocations {
+			match allocation.vesting {
+				Some(schedule) => {
+					ensure!(
+						!<Vestings<T>>::contains_key(&dao.id, &allocation.beneficiary),
+						Error::<T>::DuplicateVesting
+					);
+					T::ExposeAsset::mint(asset_id, &dao_account, allocation.amount)?;
+					<Vestings<T>>::insert(
+						&dao.id,
+						&allocation.beneficiary,
+						Vesting { amount: allocation.amount, claimed: Zero::zero(), schedule },
+					);
+					<UnvestedTotals<T>>::mutate(&dao.id, |total| {
+						total.saturating_accrue(allocation.amount)
+					});
+				},
+				None => T::ExposeAsset::mint(asset_id, &allocation.beneficiary, allocation.amount)?,
+			}
+		}
+
+		// ... and the remainder to the owner or the DAO account
+		let remainder_amount = supply - allocated;
+		if !remainder_amount.is_zero() {
+			let recipient = match remainder {
+				RemainderDestination::Owner => dao.owner.clone(),
+				RemainderDestination::DaoAccount => dao_account,
+			};
+			T::ExposeAsset::mint(asset_id, &recipient, remainder_amount)?;
+		}
+
+		// set the token metadata to the dao metadata
+		T::ExposeAsset::set_metadata(
+			asset_id,
+			&dao.owner,
+			dao.name.into(),
+			parameters.symbol,
+			parameters.decimals,
+		)?;
+
+		Self::deposit_event(Event::DaoTokenIssued { dao_id: dao.id.clone(), supply, asset_id });
+		// ... and link the dao to the asset
+		<Daos<T>>::try_mutate(dao.id, |maybe_dao| -> DispatchResult {
+			let d = maybe_dao.as_mut().ok_or(Error::<T>::DaoDoesNotExist)?;
+			d.asset_id = Some(asset_id);
+			Ok(())
+		})?;
+		Ok(asset_id)
+	}
+
+	/// The number of tokens of a vesting that have vested at block `now`.
+	///
+	/// - `vesting`: the vesting to check
+	/// - `now`: the current block
+	pub fn vested_amount(vesting: &VestingOf<T>, now: BlockNumberFor<T>) -> BalanceOf<T> {
+		let elapsed: u32 = now.saturating_sub(vesting.schedule.start).saturated_into();
+		if elapsed >= vesting.schedule.duration {
+			return vesting.amount
+		}
+		Perbill::from_rational(elapsed, vesting.schedule.duration).mul_floor(vesting.amount)
+	}
+
+	/// Create a DAO, optionally as a sub-DAO of `parent`.
+	///
+	/// - `owner`: the owner of the DAO, reserving the _DaoDeposit_ fee
+	/// - `dao_id`: unique identifier for the DAO
+	/// - `dao_name`: name of the DAO
+	/// - `parent`: the parent of the DAO, if it is a sub-DAO
+	pub fn do_create_dao(
+		owner: T::AccountId,
+		dao_id: Vec<u8>,
+		dao_name: Vec<u8>,
+		parent: Option<DaoIdOf<T>>,
+	) -> DispatchResult {
+		let id: BoundedVec<_, _> =
+			dao_id.try_into().map_err(|_| Error::<T>::DaoIdInvalidLengthTooLong)?;
+		ensure!(id.len() >= T::MinLength::get() as usize, Error::<T>::DaoIdInvalidLengthTooShort);
+		ensure!(
+			id.iter().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()),
+			Error::<T>::DaoIdInvalidChar
+		);
+		ensure!(!<Daos<T>>::contains_key(&id), Error::<T>::DaoAlreadyExists);
+
+		let name = Self::name_is_valid(dao_name)?;
+
+		let deposit = Self::deposit_for(name.len(), 0);
+		<T as Config>::Currency::reserve(&owner, deposit)?;
+		<DaoDeposits<T>>::insert(&id, deposit);
+		<OwnerDaos<T>>::insert(&owner, &id, ());
+
+		Self::deposit_event(Event::DaoCreated { owner: owner.clone(), dao_id: id.clone() });
+		if let Some(parent) = &parent {
+			<Children<T>>::insert(parent, &id, ());
+			Self::deposit_event(Event::SubDaoCreated {
+				dao_id: id.clone(),
+				parent: parent.clone(),
+			});
+		}
+		let dao = Dao {
+			id: id.clone(),
+			name,
+			owner,
+			asset_id: None,
+			meta: Default::default(),
+			meta_hash: Default::default(),
+			max_supply: None,
+			parent,
+		};
+		<Daos<T>>::insert(id, dao);
+		Ok(())
+	}
+
+	/// The deposit for a DAO with a name and metadata of the given lengths.
+	///
+	/// - `name_len`: the length of the name in bytes
+	/// - `meta_len`: the length of the metadata address in bytes
+	pub fn deposit_for(name_len: usize, meta_len: usize) -> DepositBalanceOf<T> {
+		let bytes = name_len.saturating_add(meta_len) as u32;
+		<T as Config>::DaoDeposit::get()
+			.saturating_add(T::DaoDepositPerByte::get().saturating_mul(bytes.into()))
+	}
+
+	/// The deposit reserved for a DAO, DAOs created before deposits were scaled by size have
+	/// reserved the _DaoDeposit_.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn deposit_of(dao_id: &DaoIdOf<T>) -> DepositBalanceOf<T> {
+		<DaoDeposits<T>>::get(dao_id).unwrap_or_else(<T as Config>::DaoDeposit::get)
+	}
+
+	/// The account the deposit of a DAO is reserved from, the owner unless the ownership changed
+	/// since the deposit was last updated.
+	///
+	/// - `dao`: the DAO
+	pub fn depositor_of(dao: &DaoOf<T>) -> AccountIdOf<T> {
+		<DaoDepositors<T>>::get(&dao.id).unwrap_or_else(|| dao.owner.clone())
+	}
+
+	/// Reserve or release the difference to a new deposit from the owner of the DAO. A deposit
+	/// still held by a previous owner is released to them and reserved in full from the owner.
+	///
+	/// - `dao`: the DAO
+	/// - `deposit`: the new deposit
+	pub(crate) fn update_deposit(dao: &DaoOf<T>, deposit: DepositBalanceOf<T>) -> DispatchResult {
+		let current = Self::deposit_of(&dao.id);
+		if let Some(depositor) = <DaoDepositors<T>>::get(&dao.id) {
+			<T as Config>::Currency::reserve(&dao.owner, deposit)?;
+			<T as Config>::Currency::unreserve(&depositor, current);
+			<DaoDepositors<T>>::remove(&dao.id);
+		} else if deposit > current {
+			<T as Config>::Currency::reserve(&dao.owner, deposit.saturating_sub(current))?;
+		} else {
+			<T as Config>::Currency::unreserve(&dao.owner, current.saturating_sub(deposit));
+		}
+		<DaoDeposits<T>>::insert(&dao.id, deposit);
+		Ok(())
+	}
+
+	/// The commitment to a DAO id for `create_dao_with_commitment`.
+	///
+	/// - `dao_id`: the DAO id to commit to
+	/// - `salt`: a secret salt, hiding the DAO id until it is revealed
+	/// - `who`: the account committing to and later creating the DAO
+	pub fn dao_id_commitment(dao_id: &[u8], salt: &[u8], who: &T::AccountId) -> T::Hash {
+		T::Hashing::hash_of(&(dao_id, salt, who))
+	}
+
+	/// A page of DAOs, in storage order.
+	///
+	/// - `start`: the DAO id after which the page starts, the first page starts at `None`
+	/// - `limit`: the maximum number of DAOs returned
+	pub fn daos(start: Option<Vec<u8>>, limit: u32) -> Vec<DaoOf<T>> {
+		match start {
+			None => <Daos<T>>::iter_values().take(limit as usize).collect(),
+			Some(start) => match DaoIdOf::<T>::try_from(start) {
+				Ok(start) => <Daos<T>>::iter_from(<Daos<T>>::hashed_key_for(start))
+					.map(|(_, dao)| dao)
+					.take(limit as usize)
+					.collect(),
+				// the cursor cannot be a DAO id
+				Err(_) => Vec::new(),
+			},
+		}
+	}
+
+	/// The ids of the DAOs using an asset: the DAO that issued it followed by the DAOs it is
+	/// attached to.
+	///
+	/// - `asset_id`: the asset
+	pub fn asset_daos(asset_id: &AssetIdOf<T>) -> Vec<Vec<u8>> {
+		<AssetToDao<T>>::get(asset_id)
+			.into_iter()
+			.chain(<AttachedDaos<T>>::get(asset_id))
+			.map(|dao_id| dao_id.to_vec())
+			.collect()
+	}
+
+	/// A page of the DAOs of an owner, in storage order.
+	///
+	/// - `owner`: the owner of the DAOs
+	/// - `start`: the DAO id after which the page starts, the first page starts at `None`
+	/// - `limit`: the maximum number of DAOs returned
+	pub fn daos_by_owner(owner: T::AccountId, start: Option<Vec<u8>>, limit: u32) -> Vec<DaoOf<T>> {
+		let iter = match start {
+			None => <OwnerDaos<T>>::iter_key_prefix(&owner),
+			Some(start) => match DaoIdOf::<T>::try_from(start) {
+				Ok(start) => <OwnerDaos<T>>::iter_key_prefix_from(
+					&owner,
+					<OwnerDaos<T>>::hashed_key_for(&owner, start),
+				),
+				// the cursor cannot be a DAO id
+				Err(_) => return Vec::new(),
+			},
+		};
+		iter.filter_map(<Daos<T>>::get).take(limit as usize).collect()
+	}
+
+	/// The sub-DAOs of a DAO.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn children(dao_id: &DaoIdOf<T>) -> Vec<DaoIdOf<T>> {
+		<Children<T>>::iter_key_prefix(dao_id).collect()
+	}
+
+	/// Whether a DAO has any sub-DAOs.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn has_children(dao_id: &DaoIdOf<T>) -> bool {
+		<Children<T>>::iter_key_prefix(dao_id).next().is_some()
+	}
+
+	/// Whether `child` is a sub-DAO of `parent`.
+	///
+	/// - `parent`: the unique identifier for the parent DAO
+	/// - `child`: the unique identifier for the sub-DAO
+	pub fn is_child_of(child: &DaoIdOf<T>, parent: &DaoIdOf<T>) -> bool {
+		<Children<T>>::contains_key(parent, child)
+	}
+
+	/// Whether a DAO votes by membership rather than by token.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn is_membership_dao(dao_id: &DaoIdOf<T>) -> bool {
+		<Members<T>>::contains_key(dao_id)
+	}
+
+	/// Whether `who` is a member of a membership DAO.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	/// - `who`: the account to check
+	pub fn is_member(dao_id: &DaoIdOf<T>, who: &T::AccountId) -> bool {
+		<Members<T>>::get(dao_id).map_or(false, |members| members.contains(who))
+	}
+
+	/// The number of members of a membership DAO, zero for other DAOs.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	pub fn member_count(dao_id: &DaoIdOf<T>) -> u32 {
+		<Members<T>>::decode_len(dao_id).unwrap_or_default() as u32
+	}
+
+	/// Add a member to a membership DAO, e.g. once a proposal to do so has been accepted.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	/// - `member`: the account to add
+	pub fn add_member(dao_id: &DaoIdOf<T>, member: T::AccountId) -> DispatchResult {
+		<Members<T>>::try_mutate(dao_id, |maybe_members| -> DispatchResult {
+			let members = maybe_members.as_mut().ok_or(Error::<T>::MembershipNotEnabled)?;
+			ensure!(!members.contains(&member), Error::<T>::AlreadyMember);
+			members.try_push(member.clone()).map_err(|_| Error::<T>::TooManyMembers)?;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::MemberAdded { dao_id: dao_id.clone(), member });
+		Ok(())
+	}
+
+	/// Remove a member from a membership DAO, e.g. once a proposal to do so has been accepted.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	/// - `member`: the account to remove
+	pub fn remove_member(dao_id: &DaoIdOf<T>, member: T::AccountId) -> DispatchResult {
+		<Members<T>>::try_mutate(dao_id, |maybe_members| -> DispatchResult {
+			let members = maybe_members.as_mut().ok_or(Error::<T>::MembershipNotEnabled)?;
+			ensure!(members.contains(&member), Error::<T>::NotMember);
+			ensure!(members.len() > 1, Error::<T>::CannotRemoveLastMember);
+			members.retain(|m| *m != member);
+			Ok(())
+		})?;
+		Self::deposit_event(Event::MemberRemoved { dao_id: dao_id.clone(), member });
+		Ok(())
+	}
+
+	/// Check that a DAO name has a valid length.
+	///
+	/// - `dao_name`: the name to be validated
+	pub fn name_is_valid(dao_name: Vec<u8>) -> Result<DaoNameOf<T>, Error<T>> {
+		let name: DaoNameOf<T> =
+			dao_name.try_into().map_err(|_| Error::<T>::DaoNameInvalidLengthTooLong)?;
+		ensure!(
+			name.len() >= T::MinLength::get() as usize,
+			Error::<T>::DaoNameInvalidLengthTooShort
+		);
+		Ok(name)
+	}
+
+	/// The metadata a DAO had at the given block, if it is still in the history.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	/// - `block`: the block to look up
+	pub fn metadata_at(
+		dao_id: &DaoIdOf<T>,
+		block: BlockNumberFor<T>,
+	) -> Option<MetadataRecordOf<T>> {
+		<MetadataHistory<T>>::get(dao_id)
+			.into_iter()
+			.rev()
+			.find(|record| record.set_at <= block)
+	}
+
+	/// Whether a DAO has attached the asset as its token rather than issuing it.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	/// - `asset_id`: the token of the DAO
+	pub fn is_attached(dao_id: &DaoIdOf<T>, asset_id: AssetIdOf<T>) -> bool {
+		<AttachedDaos<T>>::get(asset_id).contains(dao_id)
+	}
+
+	/// Remove a DAO from the DAOs that have attached an asset.
+	///
+	/// - `dao_id`: the unique identifier for the DAO
+	/// - `asset_id`: the attached asset
+	pub(crate) fn detach_token(dao_id: &DaoIdOf<T>, asset_id: AssetIdOf<T>) {
+		<AttachedDaos<T>>::mutate_exists(asset_id, |maybe_daos| {
+			if let Some(daos) = maybe_daos {
+				daos.retain(|id| id != dao_id);
+				if daos.is_empty() {
+					*maybe_daos = None;
+				}
+			}
+		});
+	}
+
+	/// The token a DAO has issued and therefore controls, unlike an attached one.
+	///
+	/// - `dao`: the DAO
+	pub fn issued_asset_id(dao: &DaoOf<T>) -> Result<AssetIdOf<T>, Error<T>> {
+		let asset_id = dao.asset_id.ok_or(Error::<T>::DaoTokenNotYetIssued)?;
+		ensure!(!Self::is_attached(&dao.id, asset_id), Error::<T>::DaoTokenNotOwned);
+		Ok(asset_id)
+	}
+
+	/// Change the owner of a DAO and its token, dropping any pending ownership transfer.
+	///
+	/// - `dao_id`: the DAO to transfer ownership of
+	/// - `new_owner`: the new owner
+	pub fn do_change_owner(dao_id: DaoIdOf<T>, new_owner: T::AccountId) -> DispatchResult {
+		<PendingOwners<T>>::remove(&dao_id);
+		Daos::<T>::try_mutate(dao_id.clone(), |maybe_dao| -> DispatchResult {
+			let dao = maybe_dao.as_mut().ok_or(Error::<T>::DaoDoesNotExist)?;
+			if dao.owner == new_owner {
+				return Ok(())
+			}
+			// also change asset owner if token was issued, an attached token stays with its owner
+			if let Some(asset_id) = dao.asset_id {
+				if !Self::is_attached(&dao_id, asset_id) {
+					T::ExposeAsset::change_owner(asset_id, new_owner.clone())?;
+				}
+			}
+
+			// the deposit stays with whoever reserved it, see `update_deposit`
+			let depositor = Self::depositor_of(dao);
+			if depositor == new_owner {
+				<DaoDepositors<T>>::remove(&dao_id);
+			} else {
+				<DaoDepositors<T>>::insert(&dao_id, depositor);
+			}
+			<OwnerDaos<T>>::remove(&dao.owner, &dao_id);
+			<OwnerDaos<T>>::insert(&new_owner, &dao_id, ());
+			dao.owner = new_owner.clone();
+			Ok(())
+		})?;
+		Self::deposit_event(Event::DaoOwnerChanged { dao_id, new_owner });
+		Ok(())
+	}
+
+	/// Whether the DAO issued a token that has to be destroyed before the DAO can be removed.
+	///
+	/// - `dao`: the DAO
+	pub fn token_needs_destroying(dao: &DaoOf<T>) -> bool {
+		match dao.asset_id {
+			Some(asset_id) if !Self::is_attached(&dao.id, asset_id) =>
+				T::ExposeAsset::get_asset(asset_id)
+					.map_or(false, |asset| asset.status != AssetStatus::Destroyed),
+			_ => false,
+		}
+	}
+
+	/// Remove a DAO whose token, if any, is destroyed or attached, releasing the deposit to the
+	/// account holding it.
+	///
+	/// The vestings, token offers and the state other pallets keep for the DAO are cleaned up
+	/// first, a DAO with more than _DissolveItemsLimit_ items left is dissolved instead, see
+	/// `dissolve_daos`.
+	///
+	/// - `dao`: the DAO to remove
+	pub(crate) fn do_destroy_dao(dao: DaoOf<T>) -> DispatchResult {
+		let max_items = T::DissolveItemsLimit::get();
+		if <Vestings<T>>::clear_prefix(&dao.id, max_items, None).maybe_cursor.is_some() ||
+			<TokenOffers<T>>::clear_prefix(&dao.id, max_items, None).maybe_cursor.is_some() ||
+			!T::DaoCleanup::cleanup_dao(&dao.id, max_items)
+		{
+			return Self::do_dissolve_dao(dao)
+		}
+
+		if let Some(asset_id) = dao.asset_id {
+			if Self::is_attached(&dao.id, asset_id) {
+				// an attached token is left to its owner
+				Self::detach_token(&dao.id, asset_id);
+			} else {
+				<AssetToDao<T>>::remove(asset_id);
+			}
+		}
+
+		<T as Config>::Currency::unreserve(&Self::depositor_of(&dao), Self::deposit_of(&dao.id));
+		Self::deposit_event(Event::DaoDestroyed { dao_id: dao.id.clone() });
+		if let Some(parent) = &dao.parent {
+			<Children<T>>::remove(parent, &dao.id);
+		}
+		<PendingOwners<T>>::remove(&dao.id);
+		<MetadataHistory<T>>::remove(&dao.id);
+		<Members<T>>::remove(&dao.id);
+		<UnvestedTotals<T>>::remove(&dao.id);
+		<DaoDeposits<T>>::remove(&dao.id);
+		<DaoDepositors<T>>::remove(&dao.id);
+		<OwnerDaos<T>>::remove(&dao.owner, &dao.id);
+		<Daos<T>>::remove(&dao.id);
+		Ok(())
+	}
+
+	/// Start destroying the token of a DAO and mark the DAO as dissolving, see `dissolve_daos`.
+	///
+	/// - `dao`: the DAO to dissolve
+	pub(crate) fn do_dissolve_dao(dao: DaoOf<T>) -> DispatchResult {
+		if let Some(asset_id) = dao.asset_id {
+			if !Self::is_attached(&dao.id, asset_id) {
+				ensure!(<AttachedDaos<T>>::get(asset_id).is_empty(), Error::<T>::DaoTokenShared);
+				if let Some(asset) = T::ExposeAsset::get_asset(asset_id) {
+					if Self::can_start_destroy(&asset.status) {
+						T::ExposeAsset::start_destroy(asset_id)?;
+					}
+				}
+			}
+		}
+
+		<PendingOwners<T>>::remove(&dao.id);
+		<Dissolving<T>>::insert(&dao.id, ());
+		Self::deposit_event(Event::DaoDissolutionStarted { dao_id: dao.id });
+		Ok(())
+	}
+
+	/// Store validated metadata of a DAO, keeping a record of it and updating the deposit.
+	///
+	/// - `dao`: the DAO
+	/// - `meta`: the metadata address
+	/// - `hash`: the hash of the metadata
+	pub(crate) fn do_set_metadata(
+		dao: DaoOf<T>,
+		meta: MetadataOf<T>,
+		hash: BoundedVec<u8, ConstU32<64>>,
+	) -> DispatchResult {
+		Self::update_deposit(&dao, Self::deposit_for(dao.name.len(), meta.len()))?;
+
+		Self::deposit_event(Event::DaoMetadataSet { dao_id: dao.id.clone() });
+
+		// keep a record of the metadata, dropping the oldest one if the history is full
+		let record = MetadataRecord {
+			meta: meta.clone(),
+			meta_hash: hash.clone(),
+			set_at: <frame_system::Pallet<T>>::block_number(),
+		};
+		<MetadataHistory<T>>::mutate(&dao.id, |history| {
+			if history.is_full() && !history.is_empty() {
+				history.remove(0);
+			}
+			let _ = history.try_push(record);
+		});
+
+		<Daos<T>>::try_mutate(dao.id, |maybe_dao| {
+			let dao = maybe_dao.as_mut().ok_or(Error::<T>::DaoDoesNotExist)?;
+			dao.meta = meta;
+			dao.meta_hash = hash;
+			Ok(())
+		})
+	}
+
+	/// Whether the destruction of an asset in `status` has yet to be started.
+	fn can_start_destroy(status: &AssetStatus) -> bool {
+		!matches!(status, AssetStatus::Destroying | AssetStatus::Destroyed)
+	}
+
+	/// Advance the dissolution of DAOs by one step each, as long as weight is left.
+	///
+	/// The DAOs are advanced in turn, starting after the one advanced last, so that a DAO whose
+	/// step keeps failing does not hold up the others.
+	///
+	/// Returns the weight consumed, which never exceeds `remaining_weight`.
+	pub(crate) fn dissolve_daos(remaining_weight: Weight) -> Weight {
+		let read = T::DbWeight::get().reads(1);
+		let step = <T as Config>::CoreWeightInfo::dissolve_dao_step();
+		let cursor = T::DbWeight::get().reads_writes(1, 1);
+		if !remaining_weight.all_gte(cursor) {
+			return Weight::zero()
+		}
+
+		let last = <DissolveCursor<T>>::get()
+			.map(<Dissolving<T>>::hashed_key_for)
+			.unwrap_or_else(|| <Dissolving<T>>::final_prefix().to_vec());
+		// the DAOs after the cursor, then the ones up to it
+		let in_turn = <Dissolving<T>>::iter_keys_from(last.clone()).chain(
+			<Dissolving<T>>::iter_keys()
+				.take_while(|dao_id| <Dissolving<T>>::hashed_key_for(dao_id) <= last),
+		);
+
+		let mut consumed = cursor;
+		let mut dao_ids = Vec::new();
+		for dao_id in in_turn {
+			if !remaining_weight.all_gte(consumed.saturating_add(read).saturating_add(step)) {
+				break
+			}
+			consumed.saturating_accrue(read.saturating_add(step));
+			dao_ids.push(dao_id);
+		}
+		if let Some(dao_id) = dao_ids.last() {
+			<DissolveCursor<T>>::put(dao_id);
+		}
+
+		for dao_id in dao_ids {
+			// a failing step is retried in the next block
+			let _ = Self::dissolve_dao_step(dao_id);
+		}
+
+		consumed
+	}
+
+	/// Remove up to `DissolveItemsLimit` storage items of a dissolving DAO.
+	///
+	/// The DAO token is destroyed first, then the state of other pallets is cleaned up. Once
+	/// nothing is left, the DAO is removed and the deposit released to its owner.
+	///
+	/// - `dao_id`: the DAO being dissolved
+	pub fn dissolve_dao_step(dao_id: DaoIdOf<T>) -> DispatchResult {
+		let dao = <Daos<T>>::get(&dao_id).ok_or(Error::<T>::DaoDoesNotExist)?;
+		let max_items = T::DissolveItemsLimit::get();
+
+		if let Some(asset_id) = dao.asset_id {
+			if Self::is_attached(&dao_id, asset_id) {
+				// an attached token is left to its owner
+				Self::detach_token(&dao_id, asset_id);
+			} else if let Some(asset) = T::ExposeAsset::get_asset(asset_id) {
+				// e.g. a token that was not live when the dissolution started
+				if Self::can_start_destroy(&asset.status) {
+					return T::ExposeAsset::start_destroy(asset_id)
+				}
+				if asset.status == AssetStatus::Destroying {
+					if asset.accounts > 0 {
+						T::ExposeAsset::destroy_accounts(asset_id, max_items)?;
+					} else if asset.approvals > 0 {
+						T::ExposeAsset::destroy_approvals(asset_id, max_items)?;
+					} else {
+						T::ExposeAsset::finish_destroy(asset_id)?;
+					}
+					return Ok(())
+				}
+			}
+		}
+
+		// the vesting tokens have been destroyed with the token
+		if <Vestings<T>>::clear_prefix(&dao_id, max_items, None).maybe_cursor.is_some() {
+			return Ok(())
+		}
+		if <TokenOffers<T>>::clear_prefix(&dao_id, max_items, None).maybe_cursor.is_some() {
+			return Ok(())
+		}
+
+		if !T::DaoCleanup::cleanup_dao(&dao_id, max_items) {
+			return Ok(())
+		}
+
+		<T as Config>::Currency::unreserve(&Self::depositor_of(&dao), Self::deposit_of(&dao_id));
+		if let Some(asset_id) = dao.asset_id {
+			<AssetToDao<T>>::mutate_exists(asset_id, |issuer| {
+				if issuer.as_ref() == Some(&dao_id) {
+					*issuer = None;
+				}
+			});
+		}
+		if let Some(parent) = &dao.parent {
+			<Children<T>>::remove(parent, &dao_id);
+		}
+		<Dissolving<T>>::remove(&dao_id);
+		<MetadataHistory<T>>::remove(&dao_id);
+		<Members<T>>::remove(&dao_id);
+		<UnvestedTotals<T>>::remove(&dao_id);
+		<DaoDeposits<T>>::remove(&dao_id);
+		<DaoDepositors<T>>::remove(&dao_id);
+		<OwnerDaos<T>>::remove(&dao.owner, &dao_id);
+		<Daos<T>>::remove(&dao_id);
+		Self::deposit_event(Event::DaoDestroyed { dao_id });
+		Ok(())
+	}
+
+	/// Check that a metadata hash is a SHA3-256 digest, either as 32 raw bytes or as 64 hex
+	/// characters.
+	///
+	/// - `hash`: the hash to be validated
+	pub fn hash_is_valid(hash: &[u8]) -> Result<(), Error<T>> {
+		match hash.len() {
+			32 => Ok(()),
+			64 if hash.iter().all(u8::is_ascii_hexdigit) => Ok(()),
+			64 => Err(Error::<T>::HashInvalidChar),
+			_ => Err(Error::<T>::HashInvalidWrongLength),
+		}
+	}
+
+	/// Run the custom metadata validation of the DAO, e.g. its `on_set_metadata` hook point.
+	///
+	/// - `owner`: the owner of the DAO
+	/// - `signer`: the account setting the metadata
 	/// - `meta`: the address to be validated
-	pub fn metadata_is_valid(_meta: &MetadataOf<T>) -> bool {
-		// this is currently empty, but we will offer a hook to
-		// ink! in future versions to add custom validations.
-		true
+	/// - `hash`: the hash of the metadata
+	pub fn metadata_is_valid(
+		owner: T::AccountId,
+		signer: T::AccountId,
+		meta: &MetadataOf<T>,
+		hash: &[u8],
+	) -> bool {
+		T::MetadataValidator::on_set_metadata(owner, signer, meta.to_vec(), hash.to_vec())
+	}
+}
+
+impl<T: Config> AssetDaos<AssetIdOf<T>> for Pallet<T> {
+	fn dao_ids(asset_id: &AssetIdOf<T>) -> Vec<Vec<u8>> {
+		Self::asset_daos(asset_id)
 	}
 }
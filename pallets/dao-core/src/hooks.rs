@@ -0,0 +1,34 @@
+//! Hook points of the dao-core pallet.
+use crate::Config;
+use pallet_hookpoints::Pallet as HP;
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Custom validation of DAO metadata on top of the built-in checks.
+pub trait ValidateMetadata<AccountId> {
+	/// Whether `meta` and `hash` are acceptable as metadata of a DAO owned by `owner`.
+	fn on_set_metadata(owner: AccountId, signer: AccountId, meta: Vec<u8>, hash: Vec<u8>) -> bool;
+}
+
+impl<AccountId> ValidateMetadata<AccountId> for () {
+	fn on_set_metadata(_: AccountId, _: AccountId, _: Vec<u8>, _: Vec<u8>) -> bool {
+		true
+	}
+}
+
+/// Validates metadata through the `on_set_metadata` hook point of the DAO's ink! contract.
+pub struct OnSetMetadata<T>(PhantomData<T>);
+
+impl<T: Config + pallet_hookpoints::Config> ValidateMetadata<T::AccountId> for OnSetMetadata<T> {
+	fn on_set_metadata(
+		owner: T::AccountId,
+		signer: T::AccountId,
+		meta: Vec<u8>,
+		hash: Vec<u8>,
+	) -> bool {
+		let hp = HP::<T>::create("GenesisDao::on_set_metadata", owner, signer)
+			.add_arg::<Vec<u8>>(meta)
+			.add_arg::<Vec<u8>>(hash);
+
+		HP::<T>::execute::<bool>(hp).unwrap_or(true)
+	}
+}
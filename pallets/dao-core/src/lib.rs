@@ -14,12 +14,14 @@ mod mock;
 mod tests;
 
 pub mod functions;
+pub mod hooks;
+pub mod migrations;
 
 mod types;
 pub use types::*;
 
 pub use frame_support::{
-	sp_runtime::traits::{One, Saturating},
+	sp_runtime::traits::{One, Saturating, Zero},
 	storage::bounded_vec::BoundedVec,
 	traits::{
 		tokens::fungibles::{metadata::Mutate as MetadataMutate, Mutate},
@@ -31,7 +33,9 @@ pub use frame_support::{
 pub mod weights;
 pub use crate::types::{
 	AccountIdOf, AssetIdOf, CurrencyOf, DaoIdOf, DaoNameOf, DaoOf, DepositBalanceOf, MetadataOf,
+	MetadataRecordOf, PendingOwnerOf, VestingOf,
 };
+use hooks::ValidateMetadata;
 use weights::WeightInfo;
 
 #[frame_support::pallet]
@@ -39,12 +43,15 @@ pub mod pallet {
 
 	use super::*;
 	use crate::types::DepositBalanceOf;
-	use commons::traits::pallets::AssetInterface;
-	use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
+	use commons::{
+		traits::pallets::{AssetDaos, AssetInterface, DaoCleanup},
+		types::assets::AssetStatus,
+	};
+	use frame_support::{pallet_prelude::*, traits::ReservableCurrency, PalletId};
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -76,9 +83,39 @@ pub mod pallet {
 
 		type CoreWeightInfo: WeightInfo;
 
+		/// Custom validation of DAO metadata, e.g. `hooks::OnSetMetadata` to ask the DAO's
+		/// contract.
+		type MetadataValidator: ValidateMetadata<Self::AccountId>;
+
+		/// The origin that can change the owner, set the metadata or destroy any DAO.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The number of blocks a proposed owner has to accept the ownership of a DAO.
+		#[pallet::constant]
+		type OwnershipTransferExpiry: Get<u32>;
+
+		/// Removes the state other pallets keep for a DAO when it is dissolved.
+		type DaoCleanup: DaoCleanup<DaoIdOf<Self>>;
+
+		/// The maximum number of storage items removed per DAO and block while dissolving.
+		#[pallet::constant]
+		type DissolveItemsLimit: Get<u32>;
+
+		/// The id from which the accounts of DAOs are derived.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum number of allocations in the initial distribution of a DAO token.
+		#[pallet::constant]
+		type MaxAllocations: Get<u32>;
+
 		#[pallet::constant]
 		type DaoDeposit: Get<DepositBalanceOf<Self>>;
 
+		/// The deposit per byte of the name and metadata of a DAO, on top of the _DaoDeposit_.
+		#[pallet::constant]
+		type DaoDepositPerByte: Get<DepositBalanceOf<Self>>;
+
 		#[pallet::constant]
 		type MinLength: Get<u32>;
 
@@ -93,6 +130,35 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type TokenUnits: Get<u8>;
+
+		/// The maximum number of decimals of a DAO token.
+		#[pallet::constant]
+		type MaxTokenUnits: Get<u8>;
+
+		/// The number of metadata changes kept in the history of a DAO.
+		#[pallet::constant]
+		type MaxMetadataHistory: Get<u32>;
+
+		/// The maximum number of DAOs that can attach the same token.
+		#[pallet::constant]
+		type MaxAttachedDaos: Get<u32>;
+
+		/// The maximum number of members of a membership DAO.
+		#[pallet::constant]
+		type MaxMembers: Get<u32>;
+
+		/// Whether DAO ids can only be taken with `create_dao_with_commitment`, protecting them
+		/// from being front-run.
+		type RequireDaoIdCommitment: Get<bool>;
+
+		/// The number of blocks a DAO id commitment has to age before it can be revealed.
+		#[pallet::constant]
+		type DaoIdCommitmentDelay: Get<u32>;
+
+		/// The number of blocks after which a DAO id commitment can no longer be revealed and
+		/// anyone can cancel it.
+		#[pallet::constant]
+		type DaoIdCommitmentExpiry: Get<u32>;
 	}
 
 	#[pallet::event]
@@ -103,6 +169,26 @@ pub mod pallet {
 		DaoTokenIssued { dao_id: DaoIdOf<T>, supply: BalanceOf<T>, asset_id: AssetIdOf<T> },
 		DaoMetadataSet { dao_id: DaoIdOf<T> },
 		DaoOwnerChanged { dao_id: DaoIdOf<T>, new_owner: AccountIdOf<T> },
+		DaoOwnershipProposed { dao_id: DaoIdOf<T>, new_owner: AccountIdOf<T> },
+		DaoOwnershipTransferCancelled { dao_id: DaoIdOf<T> },
+		DaoDissolutionStarted { dao_id: DaoIdOf<T> },
+		VestedTokensClaimed { dao_id: DaoIdOf<T>, who: AccountIdOf<T>, amount: BalanceOf<T> },
+		DaoTokenMinted { dao_id: DaoIdOf<T>, beneficiary: AccountIdOf<T>, amount: BalanceOf<T> },
+		DaoTokenBurned { dao_id: DaoIdOf<T>, amount: BalanceOf<T> },
+		DaoMaxSupplySet { dao_id: DaoIdOf<T>, max_supply: Option<BalanceOf<T>> },
+		DaoTokenMetadataUpdated { dao_id: DaoIdOf<T>, asset_id: AssetIdOf<T> },
+		DaoRenamed { dao_id: DaoIdOf<T>, name: DaoNameOf<T> },
+		DaoTokenOffered { dao_id: DaoIdOf<T>, asset_id: AssetIdOf<T> },
+		DaoTokenAttached { dao_id: DaoIdOf<T>, asset_id: AssetIdOf<T> },
+		SubDaoCreated { dao_id: DaoIdOf<T>, parent: DaoIdOf<T> },
+		MembershipEnabled { dao_id: DaoIdOf<T> },
+		MemberAdded { dao_id: DaoIdOf<T>, member: AccountIdOf<T> },
+		MemberRemoved { dao_id: DaoIdOf<T>, member: AccountIdOf<T> },
+		DaoIdCommitted { commitment: T::Hash, who: AccountIdOf<T> },
+		DaoIdCommitmentCancelled { commitment: T::Hash },
+		DaoOwnerForceChanged { dao_id: DaoIdOf<T>, new_owner: AccountIdOf<T> },
+		DaoMetadataForceSet { dao_id: DaoIdOf<T> },
+		DaoForceDestroyed { dao_id: DaoIdOf<T> },
 	}
 
 	#[pallet::error]
@@ -116,9 +202,48 @@ pub mod pallet {
 		DaoDoesNotExist,
 		DaoSignerNotOwner,
 		DaoTokenAlreadyIssued,
+		DaoTokenNotYetIssued,
 		MetadataInvalidLengthTooLong,
 		MetadataInvalid,
 		HashInvalidWrongLength,
+		HashInvalidChar,
+		NoPendingOwnershipTransfer,
+		SignerNotPendingOwner,
+		OwnershipTransferExpired,
+		DaoIsDissolving,
+		AllocationsExceedSupply,
+		DuplicateVesting,
+		NoVesting,
+		NothingToClaim,
+		MaxSupplyExceeded,
+		TokenSymbolInvalidLengthTooShort,
+		TokenSymbolInvalidLengthTooLong,
+		TokenSymbolInvalidChar,
+		TokenUnitsTooHigh,
+		TokenMinBalanceZero,
+		AssetDoesNotExist,
+		AssetNotLive,
+		SignerNotAssetOwner,
+		NoTokenOffer,
+		TooManyAttachedDaos,
+		DaoTokenNotOwned,
+		DaoTokenShared,
+		DaoHasChildren,
+		DaoUsesMembership,
+		MembershipNotEnabled,
+		MembershipAlreadyEnabled,
+		NoMembers,
+		TooManyMembers,
+		AlreadyMember,
+		NotMember,
+		CannotRemoveLastMember,
+		DaoIdCommitmentRequired,
+		DaoIdAlreadyCommitted,
+		NoDaoIdCommitment,
+		DaoIdCommitmentTooRecent,
+		BurnExceedsUnvestedTokens,
+		OffererNotAssetOwner,
+		DaoIdCommitmentExpired,
 	}
 
 	/// Key-Value Store of all _DAOs_, with the key being the `dao_id`.
@@ -133,6 +258,115 @@ pub mod pallet {
 	#[pallet::getter(fn get_current_asset_id)]
 	pub type CurrentAssetId<T> = StorageValue<_, AssetIdOf<T>, ValueQuery>;
 
+	/// The DAO that has issued an asset, with the key being the `asset_id`.
+	#[pallet::storage]
+	#[pallet::getter(fn get_asset_dao)]
+	pub type AssetToDao<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, DaoIdOf<T>>;
+
+	/// Ownership transfers that have been proposed but not yet accepted.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_owner)]
+	pub type PendingOwners<T: Config> =
+		StorageMap<_, Blake2_128Concat, DaoIdOf<T>, PendingOwnerOf<T>>;
+
+	/// DAOs that are being dissolved, their state is removed step by step in `on_idle`.
+	#[pallet::storage]
+	pub type Dissolving<T: Config> = StorageMap<_, Blake2_128Concat, DaoIdOf<T>, ()>;
+
+	/// The dissolving DAO that was advanced last, `dissolve_daos` continues after it.
+	#[pallet::storage]
+	pub type DissolveCursor<T: Config> = StorageValue<_, DaoIdOf<T>>;
+
+	/// Tokens of the initial distribution that vest for a beneficiary, held by the DAO account.
+	#[pallet::storage]
+	#[pallet::getter(fn get_vesting)]
+	pub type Vestings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DaoIdOf<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		VestingOf<T>,
+	>;
+
+	/// The tokens held by the DAO account that are yet to be claimed by vesting beneficiaries.
+	#[pallet::storage]
+	pub type UnvestedTotals<T: Config> =
+		StorageMap<_, Blake2_128Concat, DaoIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	/// The latest metadata changes of DAOs, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn get_metadata_history)]
+	pub type MetadataHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		DaoIdOf<T>,
+		BoundedVec<MetadataRecordOf<T>, T::MaxMetadataHistory>,
+		ValueQuery,
+	>;
+
+	/// Offers of asset owners to let a DAO attach their asset as its token, by the offering owner.
+	#[pallet::storage]
+	pub type TokenOffers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DaoIdOf<T>,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		AccountIdOf<T>,
+	>;
+
+	/// The DAOs that have attached an existing asset as their token instead of issuing one.
+	#[pallet::storage]
+	#[pallet::getter(fn get_attached_daos)]
+	pub type AttachedDaos<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		BoundedVec<DaoIdOf<T>, T::MaxAttachedDaos>,
+		ValueQuery,
+	>;
+
+	/// The DAOs of an owner, with the first key being the owner and the second the DAO.
+	#[pallet::storage]
+	pub type OwnerDaos<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, DaoIdOf<T>, ()>;
+
+	/// The sub-DAOs of a DAO, with the first key being the parent and the second the child.
+	#[pallet::storage]
+	pub type Children<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, DaoIdOf<T>, Blake2_128Concat, DaoIdOf<T>, ()>;
+
+	/// The members of membership DAOs, which vote one member one vote instead of by token.
+	#[pallet::storage]
+	#[pallet::getter(fn get_members)]
+	pub type Members<T: Config> =
+		StorageMap<_, Blake2_128Concat, DaoIdOf<T>, BoundedVec<AccountIdOf<T>, T::MaxMembers>>;
+
+	/// The deposits reserved from the owners of DAOs, scaled by the size of the name and metadata.
+	#[pallet::storage]
+	pub type DaoDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, DaoIdOf<T>, DepositBalanceOf<T>>;
+
+	/// The accounts holding the deposits of DAOs whose ownership changed, the deposit stays
+	/// reserved from a previous owner until it is next updated, see `Pallet::update_deposit`.
+	#[pallet::storage]
+	pub type DaoDepositors<T: Config> = StorageMap<_, Blake2_128Concat, DaoIdOf<T>, AccountIdOf<T>>;
+
+	/// Commitments to DAO ids that are yet to be revealed, with the committer and the block of
+	/// the commitment.
+	#[pallet::storage]
+	#[pallet::getter(fn get_dao_id_commitment)]
+	pub type DaoIdCommitments<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, (AccountIdOf<T>, BlockNumberFor<T>)>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::dissolve_daos(remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a fresh DAO.
@@ -149,65 +383,28 @@ pub mod pallet {
 			dao_name: Vec<u8>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-
-			let id: BoundedVec<_, _> =
-				dao_id.try_into().map_err(|_| Error::<T>::DaoIdInvalidLengthTooLong)?;
-			ensure!(
-				id.len() >= T::MinLength::get() as usize,
-				Error::<T>::DaoIdInvalidLengthTooShort
-			);
-			ensure!(
-				id.iter().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()),
-				Error::<T>::DaoIdInvalidChar
-			);
-			ensure!(!<Daos<T>>::contains_key(&id), Error::<T>::DaoAlreadyExists);
-
-			let name: BoundedVec<_, _> =
-				dao_name.try_into().map_err(|_| Error::<T>::DaoNameInvalidLengthTooLong)?;
-			ensure!(
-				name.len() >= T::MinLength::get() as usize,
-				Error::<T>::DaoNameInvalidLengthTooShort
-			);
-
-			<T as Config>::Currency::reserve(&sender, <T as Config>::DaoDeposit::get())?;
-
-			Self::deposit_event(Event::DaoCreated { owner: sender.clone(), dao_id: id.clone() });
-			let dao = Dao {
-				id: id.clone(),
-				name,
-				owner: sender,
-				asset_id: None,
-				meta: Default::default(),
-				meta_hash: Default::default(),
-			};
-			<Daos<T>>::insert(id, dao);
-			Ok(())
+			ensure!(!T::RequireDaoIdCommitment::get(), Error::<T>::DaoIdCommitmentRequired);
+			Self::do_create_dao(sender, dao_id, dao_name, None)
 		}
 
 		/// Destroy a DAO.
 		///
 		/// - `dao_id`: The DAO to destroy
 		///
-		/// Signer of this TX needs to be the owner of the DAO.
+		/// Signer of this TX needs to be the owner of the DAO. The proposals and other state of
+		/// the DAO are removed with it, a DAO with too much of it left is dissolved instead.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::destroy_dao())]
+		#[pallet::weight(
+			<T as pallet::Config>::CoreWeightInfo::destroy_dao()
+				.saturating_add(<T as pallet::Config>::CoreWeightInfo::dissolve_dao_step())
+		)]
 		pub fn destroy_dao(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let dao = Self::load_dao(dao_id)?;
 			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
-
-			if let Some(asset_id) = dao.asset_id {
-				if let Some(asset) = T::ExposeAsset::get_asset(asset_id) {
-					if asset.status != commons::types::assets::AssetStatus::Destroyed {
-						Err(Error::<T>::DaoTokenAlreadyIssued)?;
-					}
-				}
-			}
-
-			<T as Config>::Currency::unreserve(&sender, <T as Config>::DaoDeposit::get());
-			Self::deposit_event(Event::DaoDestroyed { dao_id: dao.id.clone() });
-			<Daos<T>>::remove(&dao.id);
-			Ok(())
+			ensure!(!Self::has_children(&dao.id), Error::<T>::DaoHasChildren);
+			ensure!(!Self::token_needs_destroying(&dao), Error::<T>::DaoTokenAlreadyIssued);
+			Self::do_destroy_dao(dao)
 		}
 
 		/// Issue the DAO token
@@ -227,40 +424,9 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 			let dao = Self::load_dao(dao_id)?;
 			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
-			ensure!(dao.asset_id.is_none(), Error::<T>::DaoTokenAlreadyIssued);
-
-			// create a fresh asset
-			<CurrentAssetId<T>>::mutate(|asset_id| asset_id.saturating_inc());
-
-			T::ExposeAsset::force_create(
-				<CurrentAssetId<T>>::get(),
-				dao.owner.clone(),
-				One::one(),
-			)?;
-
-			// and distribute it to the owner
-			T::ExposeAsset::mint(<CurrentAssetId<T>>::get(), &dao.owner, supply)?;
-
-			// set the token metadata to the dao metadata
-			T::ExposeAsset::set_metadata(
-				<CurrentAssetId<T>>::get(),
-				&dao.owner,
-				dao.name.into(),
-				dao.id.clone().into(),
-				<T as Config>::TokenUnits::get(),
-			)?;
-
-			Self::deposit_event(Event::DaoTokenIssued {
-				dao_id: dao.id.clone(),
-				supply,
-				asset_id: <CurrentAssetId<T>>::get(),
-			});
-			// ... and link the dao to the asset
-			<Daos<T>>::try_mutate(dao.id, |maybe_dao| {
-				let d = maybe_dao.as_mut().ok_or(Error::<T>::DaoDoesNotExist)?;
-				d.asset_id = Some(<CurrentAssetId<T>>::get());
-				Ok(())
-			})
+			let parameters = Self::default_token_parameters(&dao);
+			Self::do_issue_token(dao, supply, parameters, Vec::new(), RemainderDestination::Owner)?;
+			Ok(())
 		}
 
 		/// Set metadata
@@ -284,50 +450,614 @@ pub mod pallet {
 				meta.try_into().map_err(|_| Error::<T>::MetadataInvalidLengthTooLong)?;
 			let hash: BoundedVec<_, _> =
 				hash.try_into().map_err(|_| Error::<T>::HashInvalidWrongLength)?;
+			// empty metadata unsets the metadata and is always valid
+			if !(meta.is_empty() && hash.is_empty()) {
+				Self::hash_is_valid(&hash)?;
+				ensure!(
+					Self::metadata_is_valid(dao.owner.clone(), sender, &meta, &hash),
+					Error::<T>::MetadataInvalid
+				);
+			}
+			Self::do_set_metadata(dao, meta, hash)
+		}
+
+		/// Change owner immediately, only available to the `ForceOrigin`.
+		///
+		/// Owners transfer their DAO with `propose_owner` and `accept_ownership` instead.
+		///
+		/// - `dao_id`: the DAO to transfer ownership of
+		/// - `new_owner`: the new owner
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::force_change_owner())]
+		pub fn force_change_owner(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			Self::do_change_owner(dao.id.clone(), new_owner.clone())?;
+			Self::deposit_event(Event::DaoOwnerForceChanged { dao_id: dao.id, new_owner });
+			Ok(())
+		}
+
+		/// Propose a new owner for a DAO, who has to accept the ownership.
+		///
+		/// A pending proposal is replaced.
+		///
+		/// - `dao_id`: the DAO to transfer ownership of
+		/// - `new_owner`: the proposed owner
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::propose_owner())]
+		pub fn propose_owner(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+
+			let expires_at = <frame_system::Pallet<T>>::block_number()
+				.saturating_add(T::OwnershipTransferExpiry::get().into());
+			<PendingOwners<T>>::insert(
+				&dao.id,
+				PendingOwner { new_owner: new_owner.clone(), expires_at },
+			);
+			Self::deposit_event(Event::DaoOwnershipProposed { dao_id: dao.id, new_owner });
+			Ok(())
+		}
+
+		/// Accept the ownership of a DAO as its proposed owner.
+		///
+		/// - `dao_id`: the DAO to take ownership of
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::accept_ownership())]
+		pub fn accept_ownership(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			let pending =
+				<PendingOwners<T>>::get(&dao.id).ok_or(Error::<T>::NoPendingOwnershipTransfer)?;
+			ensure!(pending.new_owner == sender, Error::<T>::SignerNotPendingOwner);
 			ensure!(
-				meta.is_empty() && hash.is_empty() || Self::metadata_is_valid(&meta),
-				Error::<T>::MetadataInvalid
+				<frame_system::Pallet<T>>::block_number() <= pending.expires_at,
+				Error::<T>::OwnershipTransferExpired
 			);
+			Self::do_change_owner(dao.id, sender)
+		}
+
+		/// Cancel a proposed ownership transfer.
+		///
+		/// - `dao_id`: the DAO with the pending transfer
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::cancel_ownership_transfer())]
+		pub fn cancel_ownership_transfer(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			ensure!(
+				<PendingOwners<T>>::contains_key(&dao.id),
+				Error::<T>::NoPendingOwnershipTransfer
+			);
+			<PendingOwners<T>>::remove(&dao.id);
+			Self::deposit_event(Event::DaoOwnershipTransferCancelled { dao_id: dao.id });
+			Ok(())
+		}
+
+		/// Dissolve a DAO together with its token.
+		///
+		/// Starts destroying the DAO token and marks the DAO as dissolving. The token accounts,
+		/// the governance and the proposals of the DAO are removed in bounded steps while blocks
+		/// have weight left, after which the DAO is removed and its deposit released.
+		///
+		/// - `dao_id`: the DAO to dissolve
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::dissolve_dao())]
+		pub fn dissolve_dao(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			ensure!(!Self::has_children(&dao.id), Error::<T>::DaoHasChildren);
+			Self::do_dissolve_dao(dao)
+		}
+
+		/// Issue the DAO token and distribute its supply in one go.
+		///
+		/// - `dao_id`: The DAO for which to issue a token
+		/// - `supply`: The total supply of the token to be issued
+		/// - `allocations`: The accounts receiving parts of the supply, optionally vesting
+		/// - `remainder`: The recipient of the supply that has not been allocated
+		///
+		/// Vesting tokens are held by the DAO account and can be claimed with `claim_vested`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			<T as pallet::Config>::CoreWeightInfo::issue_token_with_distribution(
+				allocations.len() as u32
+			)
+		)]
+		pub fn issue_token_with_distribution(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			supply: BalanceOf<T>,
+			allocations: BoundedVec<AllocationOf<T>, T::MaxAllocations>,
+			remainder: RemainderDestination,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			let parameters = Self::default_token_parameters(&dao);
+			Self::do_issue_token(dao, supply, parameters, allocations.into_inner(), remainder)?;
+			Ok(())
+		}
+
+		/// Claim the tokens that have vested for the signer.
+		///
+		/// - `dao_id`: The DAO whose tokens to claim
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::claim_vested())]
+		pub fn claim_vested(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			let asset_id = dao.asset_id.ok_or(Error::<T>::DaoTokenNotYetIssued)?;
+			let mut vesting = <Vestings<T>>::get(&dao.id, &sender).ok_or(Error::<T>::NoVesting)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let amount = Self::vested_amount(&vesting, now).saturating_sub(vesting.claimed);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+			T::ExposeAsset::transfer(asset_id, &Self::dao_account_id(&dao.id), &sender, amount)?;
+			vesting.claimed.saturating_accrue(amount);
+			<UnvestedTotals<T>>::mutate(&dao.id, |total| total.saturating_reduce(amount));
+			if vesting.claimed >= vesting.amount {
+				<Vestings<T>>::remove(&dao.id, &sender);
+			} else {
+				<Vestings<T>>::insert(&dao.id, &sender, vesting);
+			}
+
+			Self::deposit_event(Event::VestedTokensClaimed { dao_id: dao.id, who: sender, amount });
+			Ok(())
+		}
+
+		/// Mint DAO tokens, up to the max supply of the DAO.
+		///
+		/// The signer needs to be the owner or the account of the DAO.
+		///
+		/// - `dao_id`: The DAO whose tokens to mint
+		/// - `beneficiary`: The account receiving the tokens
+		/// - `amount`: The number of tokens to mint
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::mint())]
+		pub fn mint(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			Self::ensure_dao_controller(&dao, &sender)?;
+			let asset_id = Self::issued_asset_id(&dao)?;
+
+			if let Some(max_supply) = dao.max_supply {
+				let supply = T::ExposeAsset::get_asset(asset_id)
+					.map(|asset| asset.supply)
+					.unwrap_or_default();
+				ensure!(supply.saturating_add(amount) <= max_supply, Error::<T>::MaxSupplyExceeded);
+			}
+
+			T::ExposeAsset::mint(asset_id, &beneficiary, amount)?;
+			Self::deposit_event(Event::DaoTokenMinted { dao_id: dao.id, beneficiary, amount });
+			Ok(())
+		}
+
+		/// Burn DAO tokens held by the DAO account.
+		///
+		/// The signer needs to be the owner or the account of the DAO. Tokens that are yet to be
+		/// claimed by vesting beneficiaries cannot be burned.
+		///
+		/// - `dao_id`: The DAO whose tokens to burn
+		/// - `amount`: The number of tokens to burn
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::burn())]
+		pub fn burn(origin: OriginFor<T>, dao_id: Vec<u8>, amount: BalanceOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			Self::ensure_dao_controller(&dao, &sender)?;
+			let asset_id = Self::issued_asset_id(&dao)?;
+
+			let dao_account = Self::dao_account_id(&dao.id);
+			let burnable = T::ExposeAsset::balance(asset_id, &dao_account)
+				.saturating_sub(<UnvestedTotals<T>>::get(&dao.id));
+			ensure!(amount <= burnable, Error::<T>::BurnExceedsUnvestedTokens);
+
+			let amount = T::ExposeAsset::burn(asset_id, &dao_account, amount)?;
+			Self::deposit_event(Event::DaoTokenBurned { dao_id: dao.id, amount });
+			Ok(())
+		}
+
+		/// Set or remove the cap on the supply of the DAO token.
+		///
+		/// The signer needs to be the owner or the account of the DAO.
+		///
+		/// - `dao_id`: The DAO to set the max supply for
+		/// - `max_supply`: The new cap, not below the current supply; `None` removes the cap
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::set_max_supply())]
+		pub fn set_max_supply(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			max_supply: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			Self::ensure_dao_controller(&dao, &sender)?;
 
-			Self::deposit_event(Event::DaoMetadataSet { dao_id: dao.id.clone() });
+			if let (Some(max_supply), Some(asset_id)) = (max_supply, dao.asset_id) {
+				let supply = T::ExposeAsset::get_asset(asset_id)
+					.map(|asset| asset.supply)
+					.unwrap_or_default();
+				ensure!(supply <= max_supply, Error::<T>::MaxSupplyExceeded);
+			}
 
-			<Daos<T>>::try_mutate(dao.id, |maybe_dao| {
+			<Daos<T>>::try_mutate(&dao.id, |maybe_dao| -> DispatchResult {
 				let dao = maybe_dao.as_mut().ok_or(Error::<T>::DaoDoesNotExist)?;
-				dao.meta = meta;
-				dao.meta_hash = hash;
+				dao.max_supply = max_supply;
 				Ok(())
-			})
+			})?;
+			Self::deposit_event(Event::DaoMaxSupplySet { dao_id: dao.id, max_supply });
+			Ok(())
 		}
 
-		/// Change owner
+		/// Issue a token with a custom symbol, decimals and minimum balance.
 		///
-		/// - `dao_id`: the DAO to transfer ownership of
-		/// - `new_owner`: the new owner
-		#[pallet::call_index(5)]
-		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::set_metadata())]
-		pub fn change_owner(
+		/// - `dao_id`: The DAO for which to issue a token
+		/// - `supply`: The total supply of the token to be issued
+		/// - `symbol`: The symbol of the token, uppercase letters and digits like a DAO id
+		/// - `decimals`: The number of decimals, at most `MaxTokenUnits`
+		/// - `min_balance`: The minimum balance of an account holding the token, not zero
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::issue_token())]
+		pub fn issue_token_with_parameters(
 			origin: OriginFor<T>,
 			dao_id: Vec<u8>,
-			new_owner: T::AccountId,
+			supply: BalanceOf<T>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			min_balance: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			let parameters = TokenParameters { symbol, decimals, min_balance };
+			Self::do_issue_token(dao, supply, parameters, Vec::new(), RemainderDestination::Owner)?;
+			Ok(())
+		}
+
+		/// Update the name and symbol of the DAO token, e.g. after the DAO has been renamed.
+		///
+		/// The token is named after the DAO, the decimals are kept. The signer needs to be the
+		/// owner or the account of the DAO.
+		///
+		/// - `dao_id`: The DAO whose token to update
+		/// - `symbol`: The new symbol of the token
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::update_token_metadata())]
+		pub fn update_token_metadata(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			symbol: Vec<u8>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			Self::ensure_dao_controller(&dao, &sender)?;
+			let asset_id = Self::issued_asset_id(&dao)?;
+			Self::symbol_is_valid(&symbol)?;
+
+			T::ExposeAsset::set_metadata(
+				asset_id,
+				&dao.owner,
+				dao.name.into(),
+				symbol,
+				T::ExposeAsset::get_decimals(asset_id),
+			)?;
+			Self::deposit_event(Event::DaoTokenMetadataUpdated { dao_id: dao.id, asset_id });
+			Ok(())
+		}
+
+		/// Rename a DAO.
+		///
+		/// The DAO token keeps its name until it is updated with `update_token_metadata`.
+		///
+		/// - `dao_id`: The DAO to rename
+		/// - `dao_name`: The new name of the DAO
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::rename_dao())]
+		pub fn rename_dao(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			dao_name: Vec<u8>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			let name = Self::name_is_valid(dao_name)?;
+			Self::update_deposit(&dao, Self::deposit_for(name.len(), dao.meta.len()))?;
+
+			<Daos<T>>::try_mutate(&dao.id, |maybe_dao| -> DispatchResult {
+				let dao = maybe_dao.as_mut().ok_or(Error::<T>::DaoDoesNotExist)?;
+				dao.name = name.clone();
+				Ok(())
+			})?;
+			Self::deposit_event(Event::DaoRenamed { dao_id: dao.id, name });
+			Ok(())
+		}
+
+		/// Offer an existing asset to a DAO as its token.
+		///
+		/// The signer needs to be the owner of the asset, who keeps control over it. The DAO can
+		/// accept the offer with `attach_token`.
+		///
+		/// - `asset_id`: The asset to offer
+		/// - `dao_id`: The DAO that may attach the asset
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::offer_token())]
+		pub fn offer_token(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			dao_id: Vec<u8>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let asset = T::ExposeAsset::get_asset(asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+			ensure!(asset.owner == sender, Error::<T>::SignerNotAssetOwner);
+			ensure!(asset.status == AssetStatus::Live, Error::<T>::AssetNotLive);
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(dao.asset_id.is_none(), Error::<T>::DaoTokenAlreadyIssued);
+
+			<TokenOffers<T>>::insert(&dao.id, asset_id, &sender);
+			Self::deposit_event(Event::DaoTokenOffered { dao_id: dao.id, asset_id });
+			Ok(())
+		}
+
+		/// Attach an asset that has been offered to the DAO as its token.
+		///
+		/// Several DAOs can share a token this way. The asset stays with its owner, so the DAO
+		/// can neither mint nor burn it.
+		///
+		/// - `dao_id`: The DAO attaching the asset
+		/// - `asset_id`: The offered asset
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::attach_token())]
+		pub fn attach_token(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			asset_id: AssetIdOf<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			let dao_id: BoundedVec<_, _> =
-				dao_id.try_into().map_err(|_| Error::<T>::DaoIdInvalidLengthTooLong)?;
-			Daos::<T>::try_mutate(dao_id.clone(), |maybe_dao| -> DispatchResult {
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			ensure!(dao.asset_id.is_none(), Error::<T>::DaoTokenAlreadyIssued);
+			ensure!(!Self::is_membership_dao(&dao.id), Error::<T>::DaoUsesMembership);
+			let offerer =
+				<TokenOffers<T>>::get(&dao.id, asset_id).ok_or(Error::<T>::NoTokenOffer)?;
+			let asset = T::ExposeAsset::get_asset(asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+			ensure!(asset.owner == offerer, Error::<T>::OffererNotAssetOwner);
+			ensure!(asset.status == AssetStatus::Live, Error::<T>::AssetNotLive);
+
+			<AttachedDaos<T>>::try_mutate(asset_id, |daos| daos.try_push(dao.id.clone()))
+				.map_err(|_| Error::<T>::TooManyAttachedDaos)?;
+			<TokenOffers<T>>::remove(&dao.id, asset_id);
+			<Daos<T>>::try_mutate(&dao.id, |maybe_dao| -> DispatchResult {
 				let dao = maybe_dao.as_mut().ok_or(Error::<T>::DaoDoesNotExist)?;
-				ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
-				if dao.owner == new_owner {
-					return Ok(())
-				}
-				// also change asset owner if token was issued
-				if let Some(asset_id) = dao.asset_id {
-					T::ExposeAsset::change_owner(asset_id, new_owner.clone())?;
-				}
-
-				dao.owner = new_owner.clone();
+				dao.asset_id = Some(asset_id);
 				Ok(())
 			})?;
-			Self::deposit_event(Event::DaoOwnerChanged { dao_id, new_owner });
+			Self::deposit_event(Event::DaoTokenAttached { dao_id: dao.id, asset_id });
+			Ok(())
+		}
+
+		/// Create a sub-DAO of an existing DAO.
+		///
+		/// The signer needs to be the owner or the account of the parent DAO and becomes the owner
+		/// of the sub-DAO, reserving the _DaoDeposit_ fee.
+		///
+		/// - `parent_id`: The DAO to create the sub-DAO for
+		/// - `dao_id`: Unique identifier for the sub-DAO, bounded by _MinLength_ & _MaxLengthId_
+		/// - `dao_name`: Name of the sub-DAO, bounded by _MinLength_ & _MaxLengthName_
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::create_sub_dao())]
+		pub fn create_sub_dao(
+			origin: OriginFor<T>,
+			parent_id: Vec<u8>,
+			dao_id: Vec<u8>,
+			dao_name: Vec<u8>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!T::RequireDaoIdCommitment::get(), Error::<T>::DaoIdCommitmentRequired);
+			let parent = Self::load_dao(parent_id)?;
+			Self::ensure_dao_controller(&parent, &sender)?;
+			Self::do_create_dao(sender, dao_id, dao_name, Some(parent.id))
+		}
+
+		/// Turn a DAO into a membership DAO, in which every member has one vote.
+		///
+		/// Membership DAOs have no token. Members are added and removed by proposal afterwards.
+		///
+		/// - `dao_id`: The DAO to enable membership for
+		/// - `members`: The initial members of the DAO
+		#[pallet::call_index(21)]
+		#[pallet::weight(
+			<T as pallet::Config>::CoreWeightInfo::enable_membership(members.len() as u32)
+		)]
+		pub fn enable_membership(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			members: BoundedVec<T::AccountId, T::MaxMembers>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(dao.owner == sender, Error::<T>::DaoSignerNotOwner);
+			ensure!(dao.asset_id.is_none(), Error::<T>::DaoTokenAlreadyIssued);
+			ensure!(!Self::is_membership_dao(&dao.id), Error::<T>::MembershipAlreadyEnabled);
+			ensure!(!members.is_empty(), Error::<T>::NoMembers);
+			for (i, member) in members.iter().enumerate() {
+				ensure!(!members[..i].contains(member), Error::<T>::AlreadyMember);
+			}
+
+			<Members<T>>::insert(&dao.id, members);
+			Self::deposit_event(Event::MembershipEnabled { dao_id: dao.id });
+			Ok(())
+		}
+
+		/// Commit to a DAO id without revealing it, to create the DAO later on with
+		/// `create_dao_with_commitment`.
+		///
+		/// The _DaoDeposit_ is reserved until the commitment is revealed or cancelled, it has to
+		/// be revealed within _DaoIdCommitmentExpiry_ blocks.
+		///
+		/// - `commitment`: The hash of the DAO id, a secret salt and the signer, see
+		///   `dao_id_commitment`
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::commit_dao_id())]
+		pub fn commit_dao_id(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				!<DaoIdCommitments<T>>::contains_key(commitment),
+				Error::<T>::DaoIdAlreadyCommitted
+			);
+
+			<T as Config>::Currency::reserve(&sender, <T as Config>::DaoDeposit::get())?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			<DaoIdCommitments<T>>::insert(commitment, (sender.clone(), now));
+			Self::deposit_event(Event::DaoIdCommitted { commitment, who: sender });
+			Ok(())
+		}
+
+		/// Create a DAO by revealing a commitment to its id.
+		///
+		/// The commitment has to be at least _DaoIdCommitmentDelay_ blocks old, so the id cannot
+		/// be taken by someone who only learns about it from this transaction.
+		///
+		/// - `dao_id`: The committed DAO id
+		/// - `dao_name`: Name of the to-be-created DAO
+		/// - `salt`: The salt of the commitment
+		/// - `parent_id`: The parent DAO if a sub-DAO is created, see `create_sub_dao`
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::create_dao_with_commitment())]
+		pub fn create_dao_with_commitment(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			dao_name: Vec<u8>,
+			salt: Vec<u8>,
+			parent_id: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let commitment = Self::dao_id_commitment(&dao_id, &salt, &sender);
+			let (_, committed_at) =
+				<DaoIdCommitments<T>>::get(commitment).ok_or(Error::<T>::NoDaoIdCommitment)?;
+			let age = <frame_system::Pallet<T>>::block_number().saturating_sub(committed_at);
+			ensure!(
+				age >= T::DaoIdCommitmentDelay::get().into(),
+				Error::<T>::DaoIdCommitmentTooRecent
+			);
+			ensure!(
+				age < T::DaoIdCommitmentExpiry::get().into(),
+				Error::<T>::DaoIdCommitmentExpired
+			);
+
+			let parent = match parent_id {
+				Some(parent_id) => {
+					let parent = Self::load_dao(parent_id)?;
+					Self::ensure_dao_controller(&parent, &sender)?;
+					Some(parent.id)
+				},
+				None => None,
+			};
+
+			<DaoIdCommitments<T>>::remove(commitment);
+			<T as Config>::Currency::unreserve(&sender, <T as Config>::DaoDeposit::get());
+			Self::do_create_dao(sender, dao_id, dao_name, parent)
+		}
+
+		/// Cancel a DAO id commitment, releasing its deposit to the committer.
+		///
+		/// - `commitment`: The commitment of the signer to cancel, or any expired commitment
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::cancel_dao_id_commitment())]
+		pub fn cancel_dao_id_commitment(
+			origin: OriginFor<T>,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (committer, committed_at) =
+				<DaoIdCommitments<T>>::get(commitment).ok_or(Error::<T>::NoDaoIdCommitment)?;
+			let age = <frame_system::Pallet<T>>::block_number().saturating_sub(committed_at);
+			ensure!(
+				committer == sender || age >= T::DaoIdCommitmentExpiry::get().into(),
+				Error::<T>::NoDaoIdCommitment
+			);
+
+			<DaoIdCommitments<T>>::remove(commitment);
+			<T as Config>::Currency::unreserve(&committer, <T as Config>::DaoDeposit::get());
+			Self::deposit_event(Event::DaoIdCommitmentCancelled { commitment });
+			Ok(())
+		}
+
+		/// Set the metadata of a DAO, only available to the `ForceOrigin`.
+		///
+		/// The metadata is not validated by the `MetadataValidator`, e.g. to replace the metadata
+		/// of a compromised DAO.
+		///
+		/// - `dao_id`: The DAO for which to set metadata
+		/// - `meta`: HTTP or IPFS address for the metadata about this DAO (description, logo)
+		/// - `hash`: SHA3 hash of the metadata to be found via `meta`
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::CoreWeightInfo::force_set_metadata())]
+		pub fn force_set_metadata(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			meta: Vec<u8>,
+			hash: Vec<u8>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+
+			let meta: BoundedVec<_, _> =
+				meta.try_into().map_err(|_| Error::<T>::MetadataInvalidLengthTooLong)?;
+			let hash: BoundedVec<_, _> =
+				hash.try_into().map_err(|_| Error::<T>::HashInvalidWrongLength)?;
+			if !(meta.is_empty() && hash.is_empty()) {
+				Self::hash_is_valid(&hash)?;
+			}
+			let dao_id = dao.id.clone();
+			Self::do_set_metadata(dao, meta, hash)?;
+			Self::deposit_event(Event::DaoMetadataForceSet { dao_id });
+			Ok(())
+		}
+
+		/// Destroy a DAO, only available to the `ForceOrigin`.
+		///
+		/// A DAO without a token is removed immediately together with its proposals and other
+		/// state, otherwise it is dissolved together with its token like with `dissolve_dao`. The
+		/// deposit is released to the owner either way.
+		///
+		/// - `dao_id`: The DAO to destroy
+		#[pallet::call_index(26)]
+		#[pallet::weight(
+			<T as pallet::Config>::CoreWeightInfo::force_destroy_dao()
+				.saturating_add(<T as pallet::Config>::CoreWeightInfo::dissolve_dao_step())
+		)]
+		pub fn force_destroy_dao(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let dao = Self::load_dao(dao_id)?;
+			ensure!(!Self::has_children(&dao.id), Error::<T>::DaoHasChildren);
+
+			let dao_id = dao.id.clone();
+			if Self::token_needs_destroying(&dao) {
+				Self::do_dissolve_dao(dao)?;
+			} else {
+				Self::do_destroy_dao(dao)?;
+			}
+			Self::deposit_event(Event::DaoForceDestroyed { dao_id });
 			Ok(())
 		}
 	}
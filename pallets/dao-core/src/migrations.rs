@@ -0,0 +1,84 @@
+//! Storage migrations for the dao-core pallet.
+use super::*;
+
+pub mod v2 {
+	use super::*;
+	use codec::{Decode, Encode, MaxEncodedLen};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+	use sp_std::marker::PhantomData;
+
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+
+	/// The DAO model up to storage version 1, before `max_supply` and `parent`.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct OldDao<DaoId, AccountId, DaoName, AssetId, Metadata> {
+		pub id: DaoId,
+		pub owner: AccountId,
+		pub name: DaoName,
+		pub asset_id: Option<AssetId>,
+		pub meta: Metadata,
+		pub meta_hash: BoundedVec<u8, ConstU32<64>>,
+	}
+
+	pub type OldDaoOf<T> =
+		OldDao<DaoIdOf<T>, AccountIdOf<T>, DaoNameOf<T>, AssetIdOf<T>, MetadataOf<T>>;
+
+	/// Migrate dao-core from storage version 1 to 2.
+	///
+	/// - adds `max_supply` and `parent` to the DAOs, both unset
+	/// - builds the `OwnerDaos` and `AssetToDao` indexes from the existing DAOs
+	///
+	/// DAOs created before version 2 keep their flat _DaoDeposit_, see `Pallet::deposit_of`.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count = 0u64;
+			<Daos<T>>::translate::<OldDaoOf<T>, _>(|_, old| {
+				count += 1;
+				<OwnerDaos<T>>::insert(&old.owner, &old.id, ());
+				if let Some(asset_id) = old.asset_id {
+					<AssetToDao<T>>::insert(asset_id, &old.id);
+				}
+				Some(Dao {
+					id: old.id,
+					owner: old.owner,
+					name: old.name,
+					asset_id: old.asset_id,
+					meta: old.meta,
+					meta_hash: old.meta_hash,
+					max_supply: None,
+					parent: None,
+				})
+			});
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(count + 1, count * 3 + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((<Daos<T>>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).map_err(|_| "cannot decode the DAO count")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 2, "storage version not updated");
+			ensure!(<Daos<T>>::iter_values().count() as u32 == count, "DAOs lost in migration");
+			for dao in <Daos<T>>::iter_values() {
+				ensure!(<OwnerDaos<T>>::contains_key(&dao.owner, &dao.id), "DAO owner not indexed");
+			}
+			Ok(())
+		}
+	}
+}
@@ -3,7 +3,8 @@ use crate::*;
 use commons::traits::pallets::ActiveProposals;
 use frame_support::{
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, ConstU8},
+	traits::{AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8},
+	PalletId,
 };
 use frame_system::pallet_prelude::*;
 use sp_core::H256;
@@ -83,6 +84,7 @@ parameter_types! {
 	// we're not really using this, as reservation is via DAO, but whatever
 	pub const ApprovalDeposit: Balance = 1;
 	pub const AssetsStringLimit: u32 = 50;
+	pub const DaoCorePalletId: PalletId = PalletId(*b"gdao/dao");
 }
 
 impl pallet_dao_assets::dao_core::Config for Test {
@@ -91,12 +93,27 @@ impl pallet_dao_assets::dao_core::Config for Test {
 	type AssetId = u32;
 	type ExposeAsset = Assets;
 	type CoreWeightInfo = ();
+	type MetadataValidator = ();
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type OwnershipTransferExpiry = ConstU32<100>;
+	type DaoCleanup = ();
+	type DissolveItemsLimit = ConstU32<5>;
+	type PalletId = DaoCorePalletId;
+	type MaxAllocations = ConstU32<10>;
 	type DaoDeposit = ConstU128<10>;
+	type DaoDepositPerByte = ConstU128<1>;
 	type MinLength = ConstU32<3>;
 	type MaxLengthId = ConstU32<8>;
 	type MaxLengthName = ConstU32<16>;
 	type MaxLengthMetadata = ConstU32<256>;
 	type TokenUnits = ConstU8<10>;
+	type MaxTokenUnits = ConstU8<18>;
+	type MaxMetadataHistory = ConstU32<3>;
+	type MaxAttachedDaos = ConstU32<3>;
+	type MaxMembers = ConstU32<10>;
+	type RequireDaoIdCommitment = ConstBool<false>;
+	type DaoIdCommitmentDelay = ConstU32<2>;
+	type DaoIdCommitmentExpiry = ConstU32<100>;
 }
 
 impl Config for Test {
@@ -105,16 +122,32 @@ impl Config for Test {
 	type AssetId = u32;
 	type ExposeAsset = Assets;
 	type CoreWeightInfo = ();
+	type MetadataValidator = ();
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type OwnershipTransferExpiry = ConstU32<100>;
+	type DaoCleanup = ();
+	type DissolveItemsLimit = ConstU32<5>;
+	type PalletId = DaoCorePalletId;
+	type MaxAllocations = ConstU32<10>;
 	type DaoDeposit = ConstU128<10>;
+	type DaoDepositPerByte = ConstU128<1>;
 	type MinLength = ConstU32<3>;
 	type MaxLengthId = ConstU32<8>;
 	type MaxLengthName = ConstU32<16>;
 	type MaxLengthMetadata = ConstU32<256>;
 	type TokenUnits = ConstU8<10>;
+	type MaxTokenUnits = ConstU8<18>;
+	type MaxMetadataHistory = ConstU32<3>;
+	type MaxAttachedDaos = ConstU32<3>;
+	type MaxMembers = ConstU32<10>;
+	type RequireDaoIdCommitment = ConstBool<false>;
+	type DaoIdCommitmentDelay = ConstU32<2>;
+	type DaoIdCommitmentExpiry = ConstU32<100>;
 }
 
 impl pallet_dao_assets::Config for Test {
 	type ActiveProposals = ActiveProposalsMock;
+	type AssetDaos = DaoCore;
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
 	type AssetIdParameter = u32;
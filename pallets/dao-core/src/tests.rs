@@ -1,6 +1,20 @@
-use crate::{mock::*, Config, Error};
-use frame_support::{assert_noop, assert_ok, traits::TypedGet};
+use crate::{
+	migrations::v2::{MigrateToV2, OldDao},
+	mock::*,
+	weights::WeightInfo,
+	Allocation, Config, DaoIdOf, DaoOf, Daos, DissolveCursor, Error, Event, RemainderDestination,
+	TokenOffers, UnvestedTotals, VestingSchedule,
+};
+use commons::{traits::pallets::AssetInterface, types::assets::AssetStatus};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion, TypedGet},
+	weights::Weight,
+	BoundedVec,
+};
 use pallet_balances::Error as BalancesError;
+use sp_runtime::traits::BadOrigin;
 
 #[test]
 fn dao_id_valid_chars() {
@@ -53,8 +67,8 @@ fn it_creates_a_dao() {
 
 		assert_eq!(Balances::free_balance(1), 1000);
 		assert_ok!(DaoCore::create_dao(RuntimeOrigin::signed(1), b"GDAO".to_vec(), b"Genesis DAO".to_vec()));
-		// reserve taken
-		assert_eq!(Balances::free_balance(1), 990);
+		// reserve taken, one per byte of the name on top of the deposit
+		assert_eq!(Balances::free_balance(1), 979);
 
 		assert_noop!(
 			DaoCore::create_dao(RuntimeOrigin::signed(1), b"GDAO".to_vec(), b"Genesis DAO".to_vec()),
@@ -110,6 +124,94 @@ fn it_destroys_a_dao() {
 	});
 }
 
+#[test]
+fn it_dissolves_a_dao() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_ok!(DaoCore::issue_token(RuntimeOrigin::signed(1), dao_id.clone(), 1000));
+		let asset_id = DaoCore::load_dao(dao_id.clone()).unwrap().asset_id.unwrap();
+		let reserved = Balances::reserved_balance(1);
+
+		// spread the token over more accounts than are removed in one step
+		let limit = <Test as Config>::DissolveItemsLimit::get();
+		for who in 2..(limit as u64 + 4) {
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), asset_id, who, 10));
+		}
+
+		assert_noop!(
+			DaoCore::dissolve_dao(RuntimeOrigin::signed(2), dao_id.clone()),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_ok!(DaoCore::dissolve_dao(RuntimeOrigin::signed(1), dao_id.clone()));
+		assert_eq!(Assets::get_asset(asset_id).unwrap().status, AssetStatus::Destroying);
+		assert_noop!(
+			DaoCore::dissolve_dao(RuntimeOrigin::signed(1), dao_id.clone()),
+			Error::<Test>::DaoIsDissolving
+		);
+		assert_noop!(
+			DaoCore::set_metadata(RuntimeOrigin::signed(1), dao_id.clone(), vec![], vec![]),
+			Error::<Test>::DaoIsDissolving
+		);
+
+		// no weight, no work
+		assert_eq!(DaoCore::on_idle(1, Weight::zero()), Weight::zero());
+
+		// the accounts are destroyed in bounded steps
+		DaoCore::on_idle(1, Weight::MAX);
+		assert_eq!(Assets::get_asset(asset_id).unwrap().accounts, 3);
+		DaoCore::on_idle(1, Weight::MAX);
+		DaoCore::on_idle(1, Weight::MAX);
+		assert_eq!(Assets::get_asset(asset_id).unwrap().status, AssetStatus::Destroyed);
+
+		// finally, the DAO is removed and the deposit released
+		DaoCore::on_idle(1, Weight::MAX);
+		let bounded_dao_id: BoundedVec<_, _> = dao_id.clone().try_into().unwrap();
+		assert_eq!(DaoCore::get_dao(&bounded_dao_id), None);
+		assert_eq!(Balances::reserved_balance(1), reserved - DaoCore::deposit_for(11, 0));
+		assert_noop!(
+			DaoCore::dissolve_dao(RuntimeOrigin::signed(1), dao_id),
+			Error::<Test>::DaoDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn dissolves_daos_in_turn() {
+	new_test_ext().execute_with(|| {
+		for dao_id in [b"GDAO".to_vec(), b"SDAO".to_vec()] {
+			assert_ok!(DaoCore::create_dao(
+				RuntimeOrigin::signed(1),
+				dao_id.clone(),
+				b"Genesis DAO".to_vec()
+			));
+			assert_ok!(DaoCore::issue_token(RuntimeOrigin::signed(1), dao_id.clone(), 1000));
+			assert_ok!(DaoCore::dissolve_dao(RuntimeOrigin::signed(1), dao_id));
+		}
+
+		// with weight for a single step, each call advances the next DAO
+		let step = <Test as Config>::CoreWeightInfo::dissolve_dao_step();
+		DaoCore::on_idle(1, step);
+		let first = DissolveCursor::<Test>::get().unwrap();
+		DaoCore::on_idle(1, step);
+		let second = DissolveCursor::<Test>::get().unwrap();
+		assert_ne!(first, second);
+		DaoCore::on_idle(1, step);
+		assert_eq!(DissolveCursor::<Test>::get(), Some(first.clone()));
+
+		// both DAOs are removed eventually
+		for _ in 0..4 {
+			DaoCore::on_idle(1, Weight::MAX);
+		}
+		assert_eq!(DaoCore::get_dao(&first), None);
+		assert_eq!(DaoCore::get_dao(&second), None);
+	});
+}
+
 #[test]
 fn issues_a_token() {
 	new_test_ext().execute_with(|| {
@@ -152,6 +254,473 @@ fn issues_a_token() {
 	});
 }
 
+#[test]
+fn issues_a_token_with_parameters() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+
+		let issue = |symbol: &[u8], decimals, min_balance| {
+			DaoCore::issue_token_with_parameters(
+				RuntimeOrigin::signed(1),
+				dao_id.clone(),
+				1000,
+				symbol.to_vec(),
+				decimals,
+				min_balance,
+			)
+		};
+		assert_noop!(issue(b"GD", 12, 10), Error::<Test>::TokenSymbolInvalidLengthTooShort);
+		assert_noop!(issue(b"GENESISDT", 12, 10), Error::<Test>::TokenSymbolInvalidLengthTooLong);
+		assert_noop!(issue(b"gdt", 12, 10), Error::<Test>::TokenSymbolInvalidChar);
+		assert_noop!(issue(b"GDT", 19, 10), Error::<Test>::TokenUnitsTooHigh);
+		assert_noop!(issue(b"GDT", 12, 0), Error::<Test>::TokenMinBalanceZero);
+		assert_ok!(issue(b"GDT", 12, 10));
+
+		let dao = DaoCore::load_dao(dao_id.clone()).unwrap();
+		let asset_id = dao.asset_id.unwrap();
+		use frame_support::traits::tokens::fungibles::metadata::Inspect;
+		assert_eq!(Assets::name(asset_id), b"Genesis DAO".to_vec());
+		assert_eq!(Assets::symbol(asset_id), b"GDT".to_vec());
+		assert_eq!(Assets::decimals(asset_id), 12);
+		assert_eq!(Assets::get_asset(asset_id).unwrap().min_balance, 10);
+
+		// the DAO is found by the asset, even though the symbol differs from its id
+		assert_eq!(DaoCore::get_asset_dao(asset_id), Some(dao.id.clone()));
+		assert_eq!(Assets::dao_ids(&asset_id), vec![b"GDAO".to_vec()]);
+
+		// the symbol can be updated later on, keeping the decimals
+		assert_noop!(
+			DaoCore::update_token_metadata(
+				RuntimeOrigin::signed(2),
+				dao_id.clone(),
+				b"GD2".to_vec()
+			),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_noop!(
+			DaoCore::update_token_metadata(RuntimeOrigin::signed(1), dao_id.clone(), b"g".to_vec()),
+			Error::<Test>::TokenSymbolInvalidLengthTooShort
+		);
+		assert_ok!(DaoCore::update_token_metadata(
+			RuntimeOrigin::signed(1),
+			dao_id,
+			b"GD2".to_vec()
+		));
+		assert_eq!(Assets::symbol(asset_id), b"GD2".to_vec());
+		assert_eq!(Assets::decimals(asset_id), 12);
+	});
+}
+
+#[test]
+fn issues_a_token_with_distribution() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+
+		let vesting = VestingSchedule { start: 1, duration: 100 };
+		let allocation = |beneficiary, amount, vesting| Allocation { beneficiary, amount, vesting };
+
+		// the allocations must not exceed the supply
+		let allocations: BoundedVec<_, _> =
+			vec![allocation(2, 600, None), allocation(3, 500, None)].try_into().unwrap();
+		assert_noop!(
+			DaoCore::issue_token_with_distribution(
+				RuntimeOrigin::signed(1),
+				dao_id.clone(),
+				1000,
+				allocations,
+				RemainderDestination::Owner
+			),
+			Error::<Test>::AllocationsExceedSupply
+		);
+
+		// a beneficiary can only vest once
+		let allocations: BoundedVec<_, _> = vec![
+			allocation(2, 100, Some(vesting.clone())),
+			allocation(2, 100, Some(vesting.clone())),
+		]
+		.try_into()
+		.unwrap();
+		assert_noop!(
+			DaoCore::issue_token_with_distribution(
+				RuntimeOrigin::signed(1),
+				dao_id.clone(),
+				1000,
+				allocations,
+				RemainderDestination::Owner
+			),
+			Error::<Test>::DuplicateVesting
+		);
+
+		let allocations: BoundedVec<_, _> =
+			vec![allocation(2, 100, None), allocation(3, 200, Some(vesting))]
+				.try_into()
+				.unwrap();
+		assert_ok!(DaoCore::issue_token_with_distribution(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			1000,
+			allocations,
+			RemainderDestination::DaoAccount
+		));
+
+		let dao = DaoCore::load_dao(dao_id).unwrap();
+		let asset_id = dao.asset_id.unwrap();
+		let dao_account = DaoCore::dao_account_id(&dao.id);
+		assert_eq!(Assets::total_supply(asset_id), 1000);
+		assert_eq!(Assets::balance(asset_id, 1), 0);
+		assert_eq!(Assets::balance(asset_id, 2), 100);
+		assert_eq!(Assets::balance(asset_id, 3), 0);
+		assert_eq!(Assets::balance(asset_id, dao_account), 900);
+		assert_eq!(DaoCore::get_vesting(&dao.id, 3).unwrap().amount, 200);
+	});
+}
+
+#[test]
+fn can_claim_vested_tokens() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+		let allocations: BoundedVec<_, _> = vec![Allocation {
+			beneficiary: 2,
+			amount: 200,
+			vesting: Some(VestingSchedule { start: 1, duration: 100 }),
+		}]
+		.try_into()
+		.unwrap();
+		assert_ok!(DaoCore::issue_token_with_distribution(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			1000,
+			allocations,
+			RemainderDestination::Owner
+		));
+		let dao = DaoCore::load_dao(dao_id.clone()).unwrap();
+		let asset_id = dao.asset_id.unwrap();
+		assert_eq!(Assets::balance(asset_id, 1), 800);
+
+		assert_noop!(
+			DaoCore::claim_vested(RuntimeOrigin::signed(3), dao_id.clone()),
+			Error::<Test>::NoVesting
+		);
+		assert_noop!(
+			DaoCore::claim_vested(RuntimeOrigin::signed(2), dao_id.clone()),
+			Error::<Test>::NothingToClaim
+		);
+
+		// a quarter has vested
+		System::set_block_number(26);
+		assert_ok!(DaoCore::claim_vested(RuntimeOrigin::signed(2), dao_id.clone()));
+		assert_eq!(Assets::balance(asset_id, 2), 50);
+		assert_eq!(DaoCore::get_vesting(&dao.id, 2).unwrap().claimed, 50);
+
+		// the unvested tokens cannot be burned from the DAO account
+		let dao_account = DaoCore::dao_account_id(&dao.id);
+		assert_ok!(DaoCore::mint(RuntimeOrigin::signed(1), dao_id.clone(), dao_account, 100));
+		assert_noop!(
+			DaoCore::burn(RuntimeOrigin::signed(1), dao_id.clone(), 101),
+			Error::<Test>::BurnExceedsUnvestedTokens
+		);
+		assert_ok!(DaoCore::burn(RuntimeOrigin::signed(1), dao_id.clone(), 100));
+		assert_eq!(Assets::balance(asset_id, dao_account), 150);
+
+		// everything has vested
+		System::set_block_number(200);
+		assert_ok!(DaoCore::claim_vested(RuntimeOrigin::signed(2), dao_id.clone()));
+		assert_eq!(Assets::balance(asset_id, 2), 200);
+		assert_eq!(DaoCore::get_vesting(&dao.id, 2), None);
+		assert_eq!(UnvestedTotals::<Test>::get(&dao.id), 0);
+		assert_noop!(
+			DaoCore::claim_vested(RuntimeOrigin::signed(2), dao_id),
+			Error::<Test>::NoVesting
+		);
+	});
+}
+
+#[test]
+fn can_mint_and_burn_tokens() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_noop!(
+			DaoCore::mint(RuntimeOrigin::signed(1), dao_id.clone(), 2, 100),
+			Error::<Test>::DaoTokenNotYetIssued
+		);
+
+		// the supply can be capped before the token is issued
+		assert_ok!(DaoCore::set_max_supply(RuntimeOrigin::signed(1), dao_id.clone(), Some(500)));
+		assert_noop!(
+			DaoCore::issue_token(RuntimeOrigin::signed(1), dao_id.clone(), 1000),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_ok!(DaoCore::set_max_supply(RuntimeOrigin::signed(1), dao_id.clone(), Some(1200)));
+		assert_ok!(DaoCore::issue_token_with_distribution(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			1000,
+			Default::default(),
+			RemainderDestination::DaoAccount
+		));
+		let dao = DaoCore::load_dao(dao_id.clone()).unwrap();
+		let asset_id = dao.asset_id.unwrap();
+		let dao_account = DaoCore::dao_account_id(&dao.id);
+
+		// only the owner or the DAO account control the supply
+		assert_noop!(
+			DaoCore::mint(RuntimeOrigin::signed(2), dao_id.clone(), 2, 100),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_noop!(
+			DaoCore::burn(RuntimeOrigin::signed(2), dao_id.clone(), 100),
+			Error::<Test>::DaoSignerNotOwner
+		);
+
+		assert_ok!(DaoCore::mint(RuntimeOrigin::signed(1), dao_id.clone(), 2, 100));
+		assert_ok!(DaoCore::mint(RuntimeOrigin::signed(dao_account), dao_id.clone(), 2, 100));
+		assert_eq!(Assets::balance(asset_id, 2), 200);
+		assert_eq!(Assets::total_supply(asset_id), 1200);
+		assert_noop!(
+			DaoCore::mint(RuntimeOrigin::signed(1), dao_id.clone(), 2, 1),
+			Error::<Test>::MaxSupplyExceeded
+		);
+
+		// the supply history follows minting and burning
+		System::set_block_number(2);
+		assert_ok!(DaoCore::burn(RuntimeOrigin::signed(1), dao_id.clone(), 300));
+		assert_eq!(Assets::balance(asset_id, dao_account), 700);
+		assert_eq!(Assets::total_supply(asset_id), 900);
+		assert_eq!(Assets::total_historical_supply(asset_id, 1), Some(1200));
+		assert_eq!(Assets::total_historical_supply(asset_id, 2), Some(900));
+		assert_eq!(Assets::total_historical_balance(asset_id, 2, 1).1, 200);
+
+		// the cap cannot be set below the supply
+		assert_noop!(
+			DaoCore::set_max_supply(RuntimeOrigin::signed(1), dao_id.clone(), Some(800)),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_ok!(DaoCore::set_max_supply(RuntimeOrigin::signed(1), dao_id.clone(), None));
+		assert_ok!(DaoCore::mint(RuntimeOrigin::signed(1), dao_id, 2, 1000));
+	});
+}
+
+#[test]
+fn can_attach_a_token() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		let spin_off = b"SDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_ok!(DaoCore::issue_token(RuntimeOrigin::signed(1), dao_id.clone(), 1000));
+		let asset_id = DaoCore::load_dao(dao_id.clone()).unwrap().asset_id.unwrap();
+
+		// the spin-off is handed over to another owner
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			spin_off.clone(),
+			b"Spin-off DAO".to_vec()
+		));
+		assert_ok!(DaoCore::force_change_owner(RuntimeOrigin::root(), spin_off.clone(), 2));
+
+		// the asset owner needs to consent
+		assert_noop!(
+			DaoCore::attach_token(RuntimeOrigin::signed(2), spin_off.clone(), asset_id),
+			Error::<Test>::NoTokenOffer
+		);
+		assert_noop!(
+			DaoCore::offer_token(RuntimeOrigin::signed(2), asset_id, spin_off.clone()),
+			Error::<Test>::SignerNotAssetOwner
+		);
+		assert_noop!(
+			DaoCore::offer_token(RuntimeOrigin::signed(1), 99, spin_off.clone()),
+			Error::<Test>::AssetDoesNotExist
+		);
+		assert_noop!(
+			DaoCore::offer_token(RuntimeOrigin::signed(1), asset_id, dao_id.clone()),
+			Error::<Test>::DaoTokenAlreadyIssued
+		);
+		assert_ok!(DaoCore::offer_token(RuntimeOrigin::signed(1), asset_id, spin_off.clone()));
+
+		assert_noop!(
+			DaoCore::attach_token(RuntimeOrigin::signed(1), spin_off.clone(), asset_id),
+			Error::<Test>::DaoSignerNotOwner
+		);
+
+		// the offer only holds while the offerer owns the asset
+		assert_ok!(Assets::change_owner(asset_id, 4));
+		assert_noop!(
+			DaoCore::attach_token(RuntimeOrigin::signed(2), spin_off.clone(), asset_id),
+			Error::<Test>::OffererNotAssetOwner
+		);
+		assert_ok!(Assets::change_owner(asset_id, 1));
+		assert_ok!(DaoCore::attach_token(RuntimeOrigin::signed(2), spin_off.clone(), asset_id));
+		let dao = DaoCore::load_dao(spin_off.clone()).unwrap();
+		assert_eq!(dao.asset_id, Some(asset_id));
+		assert_eq!(DaoCore::get_attached_daos(asset_id).to_vec(), vec![dao.id.clone()]);
+		assert_eq!(Assets::dao_ids(&asset_id), vec![dao_id.clone(), spin_off.clone()]);
+		System::assert_last_event(Event::DaoTokenAttached { dao_id: dao.id, asset_id }.into());
+
+		// the token stays under the control of its owner
+		assert_noop!(
+			DaoCore::mint(RuntimeOrigin::signed(2), spin_off.clone(), 2, 100),
+			Error::<Test>::DaoTokenNotOwned
+		);
+		assert_ok!(DaoCore::force_change_owner(RuntimeOrigin::root(), spin_off.clone(), 3));
+		assert_eq!(Assets::get_asset(asset_id).unwrap().owner, 1);
+		assert_noop!(
+			DaoCore::dissolve_dao(RuntimeOrigin::signed(1), dao_id.clone()),
+			Error::<Test>::DaoTokenShared
+		);
+
+		// destroying the spin-off detaches the token
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(3), spin_off));
+		assert!(DaoCore::get_attached_daos(asset_id).is_empty());
+		assert_eq!(Assets::get_asset(asset_id).unwrap().status, AssetStatus::Live);
+
+		// ... and offers to a destroyed DAO are removed with it
+		let other = b"ODAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			other.clone(),
+			b"Other DAO".to_vec()
+		));
+		assert_ok!(DaoCore::offer_token(RuntimeOrigin::signed(1), asset_id, other.clone()));
+		let other_id = DaoCore::load_dao(other.clone()).unwrap().id;
+		assert!(TokenOffers::<Test>::contains_key(&other_id, asset_id));
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(1), other));
+		assert!(!TokenOffers::<Test>::contains_key(&other_id, asset_id));
+		assert_ok!(DaoCore::dissolve_dao(RuntimeOrigin::signed(1), dao_id));
+	});
+}
+
+#[test]
+fn can_create_sub_daos() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		let child = b"SDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+
+		// only the owner or the account of the parent can create sub-DAOs
+		assert_noop!(
+			DaoCore::create_sub_dao(
+				RuntimeOrigin::signed(2),
+				dao_id.clone(),
+				child.clone(),
+				b"Sub DAO".to_vec()
+			),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_ok!(DaoCore::create_sub_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			child.clone(),
+			b"Sub DAO".to_vec()
+		));
+
+		let parent = DaoCore::load_dao(dao_id.clone()).unwrap();
+		let sub_dao = DaoCore::load_dao(child.clone()).unwrap();
+		assert_eq!(sub_dao.owner, 1);
+		assert_eq!(sub_dao.parent, Some(parent.id.clone()));
+		assert_eq!(DaoCore::children(&parent.id), vec![sub_dao.id.clone()]);
+		assert!(DaoCore::is_child_of(&sub_dao.id, &parent.id));
+		System::assert_last_event(
+			Event::SubDaoCreated { dao_id: sub_dao.id.clone(), parent: parent.id.clone() }.into(),
+		);
+
+		// a parent can only be destroyed once its sub-DAOs are gone
+		assert_noop!(
+			DaoCore::destroy_dao(RuntimeOrigin::signed(1), dao_id.clone()),
+			Error::<Test>::DaoHasChildren
+		);
+		assert_noop!(
+			DaoCore::dissolve_dao(RuntimeOrigin::signed(1), dao_id.clone()),
+			Error::<Test>::DaoHasChildren
+		);
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(1), child));
+		assert!(DaoCore::children(&parent.id).is_empty());
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(1), dao_id));
+	});
+}
+
+#[test]
+fn can_enable_membership() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+		let members = |accounts: Vec<u64>| -> BoundedVec<u64, _> { accounts.try_into().unwrap() };
+
+		assert_noop!(
+			DaoCore::enable_membership(RuntimeOrigin::signed(2), dao_id.clone(), members(vec![1])),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_noop!(
+			DaoCore::enable_membership(RuntimeOrigin::signed(1), dao_id.clone(), members(vec![])),
+			Error::<Test>::NoMembers
+		);
+		assert_noop!(
+			DaoCore::enable_membership(
+				RuntimeOrigin::signed(1),
+				dao_id.clone(),
+				members(vec![1, 2, 1])
+			),
+			Error::<Test>::AlreadyMember
+		);
+		assert_ok!(DaoCore::enable_membership(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			members(vec![1, 2])
+		));
+
+		let id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		assert!(DaoCore::is_membership_dao(&id));
+		assert!(DaoCore::is_member(&id, &2));
+		assert_eq!(DaoCore::member_count(&id), 2);
+
+		// membership DAOs have no token
+		assert_noop!(
+			DaoCore::issue_token(RuntimeOrigin::signed(1), dao_id.clone(), 1000),
+			Error::<Test>::DaoUsesMembership
+		);
+
+		assert_ok!(DaoCore::add_member(&id, 3));
+		assert_noop!(DaoCore::add_member(&id, 3), Error::<Test>::AlreadyMember);
+		assert_ok!(DaoCore::remove_member(&id, 1));
+		assert_ok!(DaoCore::remove_member(&id, 2));
+		assert_noop!(DaoCore::remove_member(&id, 3), Error::<Test>::CannotRemoveLastMember);
+		assert_eq!(DaoCore::get_members(&id).unwrap().to_vec(), vec![3]);
+
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(1), dao_id));
+		assert!(!DaoCore::is_membership_dao(&id));
+	});
+}
+
 #[test]
 fn it_sets_metadata() {
 	new_test_ext().execute_with(|| {
@@ -189,11 +758,115 @@ fn it_sets_metadata() {
 }
 
 #[test]
-fn can_change_owner() {
+fn keeps_a_metadata_history() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = b"GDAO".to_vec();
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"Genesis DAO".to_vec()));
+
+		// MaxMetadataHistory is 3 in the mock, so the first change is dropped
+		for block in 1..=4 {
+			System::set_block_number(block * 10);
+			let metadata = format!("http://my.cool.dao/{block}").into_bytes();
+			assert_ok!(DaoCore::set_metadata(
+				origin.clone(),
+				dao_id.clone(),
+				metadata,
+				hash.clone()
+			));
+		}
+
+		let id: DaoIdOf<Test> = dao_id.clone().try_into().unwrap();
+		let history = DaoCore::get_metadata_history(&id);
+		assert_eq!(history.len(), 3);
+		assert_eq!(history[0].set_at, 20);
+		assert_eq!(history[2].meta.to_vec(), b"http://my.cool.dao/4".to_vec());
+
+		assert_eq!(DaoCore::metadata_at(&id, 15), None);
+		assert_eq!(DaoCore::metadata_at(&id, 25).unwrap().meta.to_vec(), b"http://my.cool.dao/2");
+		assert_eq!(DaoCore::metadata_at(&id, 40).unwrap().set_at, 40);
+
+		assert_ok!(DaoCore::destroy_dao(origin, dao_id));
+		assert!(DaoCore::get_metadata_history(&id).is_empty());
+	});
+}
+
+#[test]
+fn can_rename_dao() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+
+		assert_noop!(
+			DaoCore::rename_dao(RuntimeOrigin::signed(2), dao_id.clone(), b"New Name".to_vec()),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_noop!(
+			DaoCore::rename_dao(RuntimeOrigin::signed(1), dao_id.clone(), b"N".to_vec()),
+			Error::<Test>::DaoNameInvalidLengthTooShort
+		);
+		assert_noop!(
+			DaoCore::rename_dao(RuntimeOrigin::signed(1), dao_id.clone(), vec![b'N'; 256]),
+			Error::<Test>::DaoNameInvalidLengthTooLong
+		);
+
+		assert_ok!(DaoCore::rename_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"New Name".to_vec()
+		));
+		let dao = DaoCore::load_dao(dao_id.clone()).unwrap();
+		assert_eq!(dao.name.to_vec(), b"New Name".to_vec());
+		System::assert_last_event(Event::DaoRenamed { dao_id: dao.id, name: dao.name }.into());
+	});
+}
+
+#[test]
+fn it_validates_the_metadata_hash() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		let dao_id = b"GDAO".to_vec();
+		let metadata = b"http://my.cool.dao".to_vec();
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"Genesis DAO".to_vec()));
+
+		// a hash needs to be a hex encoded or raw 32 byte digest
+		assert_noop!(
+			DaoCore::set_metadata(origin.clone(), dao_id.clone(), metadata.clone(), vec![]),
+			Error::<Test>::HashInvalidWrongLength
+		);
+		assert_noop!(
+			DaoCore::set_metadata(origin.clone(), dao_id.clone(), metadata.clone(), vec![b'a'; 63]),
+			Error::<Test>::HashInvalidWrongLength
+		);
+		assert_noop!(
+			DaoCore::set_metadata(origin.clone(), dao_id.clone(), metadata.clone(), vec![b'x'; 64]),
+			Error::<Test>::HashInvalidChar
+		);
+		assert_ok!(DaoCore::set_metadata(
+			origin.clone(),
+			dao_id.clone(),
+			metadata.clone(),
+			vec![b'F'; 64]
+		));
+		assert_ok!(DaoCore::set_metadata(origin.clone(), dao_id.clone(), metadata, vec![0xff; 32]));
+
+		// metadata can be unset
+		assert_ok!(DaoCore::set_metadata(origin, dao_id.clone(), vec![], vec![]));
+		assert!(DaoCore::load_dao(dao_id).unwrap().meta.is_empty());
+	});
+}
+
+#[test]
+fn can_force_change_owner() {
 	new_test_ext().execute_with(|| {
 		let new_owner = 61;
 		assert_noop!(
-			DaoCore::change_owner(RuntimeOrigin::signed(1), b"NONE".to_vec(), new_owner),
+			DaoCore::force_change_owner(RuntimeOrigin::root(), b"NONE".to_vec(), new_owner),
 			Error::<Test>::DaoDoesNotExist
 		);
 
@@ -208,10 +881,415 @@ fn can_change_owner() {
 		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
 		assert_eq!(dao.owner, creator);
 
+		// only the force origin can change the owner immediately
+		assert_noop!(
+			DaoCore::force_change_owner(
+				RuntimeOrigin::signed(creator),
+				b"GDAO".to_vec(),
+				new_owner
+			),
+			BadOrigin
+		);
+
 		// change owner
-		assert_ok!(DaoCore::change_owner(RuntimeOrigin::signed(1), b"GDAO".to_vec(), new_owner));
+		assert_ok!(DaoCore::force_change_owner(RuntimeOrigin::root(), b"GDAO".to_vec(), new_owner));
 
 		let dao = DaoCore::load_dao(b"GDAO".to_vec()).unwrap();
 		assert_eq!(dao.owner, new_owner);
+		System::assert_last_event(Event::DaoOwnerForceChanged { dao_id: dao.id, new_owner }.into());
+	});
+}
+
+#[test]
+fn can_force_set_metadata() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		let metadata = b"http://my.cool.dao".to_vec();
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+
+		assert_noop!(
+			DaoCore::force_set_metadata(
+				RuntimeOrigin::signed(1),
+				dao_id.clone(),
+				metadata.clone(),
+				hash.clone()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			DaoCore::force_set_metadata(
+				RuntimeOrigin::root(),
+				dao_id.clone(),
+				metadata.clone(),
+				b"invalid".to_vec()
+			),
+			Error::<Test>::HashInvalidWrongLength
+		);
+
+		assert_ok!(DaoCore::force_set_metadata(
+			RuntimeOrigin::root(),
+			dao_id.clone(),
+			metadata.clone(),
+			hash.clone()
+		));
+		let dao = DaoCore::load_dao(dao_id).unwrap();
+		assert_eq!(dao.meta, metadata);
+		assert_eq!(dao.meta_hash, hash);
+		assert_eq!(DaoCore::get_metadata_history(&dao.id).len(), 1);
+		System::assert_last_event(Event::DaoMetadataForceSet { dao_id: dao.id }.into());
+	});
+}
+
+#[test]
+fn can_force_destroy_a_dao() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		let bounded_dao_id: BoundedVec<_, _> = dao_id.clone().try_into().unwrap();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+
+		assert_noop!(
+			DaoCore::force_destroy_dao(RuntimeOrigin::signed(1), dao_id.clone()),
+			BadOrigin
+		);
+
+		// a DAO without a token is removed immediately
+		assert_ok!(DaoCore::force_destroy_dao(RuntimeOrigin::root(), dao_id.clone()));
+		assert_eq!(DaoCore::get_dao(&bounded_dao_id), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(
+			Event::DaoForceDestroyed { dao_id: bounded_dao_id.clone() }.into(),
+		);
+
+		// a DAO with a token is dissolved
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_ok!(DaoCore::issue_token(RuntimeOrigin::signed(1), dao_id.clone(), 1000));
+		let asset_id = DaoCore::load_dao(dao_id.clone()).unwrap().asset_id.unwrap();
+		assert_ok!(DaoCore::force_destroy_dao(RuntimeOrigin::root(), dao_id.clone()));
+		assert_eq!(Assets::get_asset(asset_id).unwrap().status, AssetStatus::Destroying);
+		assert_noop!(
+			DaoCore::force_destroy_dao(RuntimeOrigin::root(), dao_id),
+			Error::<Test>::DaoIsDissolving
+		);
+
+		// the accounts, the token and finally the DAO are removed
+		for _ in 0..3 {
+			DaoCore::on_idle(1, Weight::MAX);
+		}
+		assert_eq!(DaoCore::get_dao(&bounded_dao_id), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn can_transfer_ownership_in_two_steps() {
+	new_test_ext().execute_with(|| {
+		let creator = 1;
+		let new_owner = 2;
+		let dao_id = b"GDAO".to_vec();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(creator),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+
+		assert_noop!(
+			DaoCore::propose_owner(RuntimeOrigin::signed(new_owner), dao_id.clone(), new_owner),
+			Error::<Test>::DaoSignerNotOwner
+		);
+		assert_noop!(
+			DaoCore::accept_ownership(RuntimeOrigin::signed(new_owner), dao_id.clone()),
+			Error::<Test>::NoPendingOwnershipTransfer
+		);
+
+		// a proposal can be cancelled by the owner
+		assert_ok!(DaoCore::propose_owner(RuntimeOrigin::signed(creator), dao_id.clone(), 3));
+		assert_ok!(DaoCore::cancel_ownership_transfer(
+			RuntimeOrigin::signed(creator),
+			dao_id.clone()
+		));
+		assert_noop!(
+			DaoCore::accept_ownership(RuntimeOrigin::signed(3), dao_id.clone()),
+			Error::<Test>::NoPendingOwnershipTransfer
+		);
+
+		// only the proposed owner can accept
+		assert_ok!(DaoCore::propose_owner(
+			RuntimeOrigin::signed(creator),
+			dao_id.clone(),
+			new_owner
+		));
+		assert_noop!(
+			DaoCore::accept_ownership(RuntimeOrigin::signed(3), dao_id.clone()),
+			Error::<Test>::SignerNotPendingOwner
+		);
+		let dao = DaoCore::load_dao(dao_id.clone()).unwrap();
+		assert_eq!(dao.owner, creator);
+
+		// the proposal expires
+		let expires_at =
+			System::block_number() + <Test as Config>::OwnershipTransferExpiry::get() as u64;
+		System::set_block_number(expires_at + 1);
+		assert_noop!(
+			DaoCore::accept_ownership(RuntimeOrigin::signed(new_owner), dao_id.clone()),
+			Error::<Test>::OwnershipTransferExpired
+		);
+
+		assert_ok!(DaoCore::propose_owner(
+			RuntimeOrigin::signed(creator),
+			dao_id.clone(),
+			new_owner
+		));
+		assert_ok!(DaoCore::accept_ownership(RuntimeOrigin::signed(new_owner), dao_id.clone()));
+		let dao = DaoCore::load_dao(dao_id.clone()).unwrap();
+		assert_eq!(dao.owner, new_owner);
+		assert_eq!(DaoCore::get_pending_owner(dao.id), None);
+	});
+}
+
+#[test]
+fn scales_the_deposit_with_the_storage_footprint() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		let bounded_dao_id: BoundedVec<_, _> = dao_id.clone().try_into().unwrap();
+		let deposit = <Test as Config>::DaoDeposit::get();
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+		assert_eq!(Balances::reserved_balance(1), deposit + 11);
+		assert_eq!(DaoCore::deposit_of(&bounded_dao_id), deposit + 11);
+
+		// setting metadata reserves more
+		let meta = b"http://my.cool.dao".to_vec();
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+		assert_ok!(DaoCore::set_metadata(RuntimeOrigin::signed(1), dao_id.clone(), meta, hash));
+		assert_eq!(Balances::reserved_balance(1), deposit + 11 + 18);
+
+		// a shorter name releases the difference
+		assert_ok!(DaoCore::rename_dao(RuntimeOrigin::signed(1), dao_id.clone(), b"GDAO".to_vec()));
+		assert_eq!(Balances::reserved_balance(1), deposit + 4 + 18);
+
+		// unsetting the metadata releases its part
+		assert_ok!(DaoCore::set_metadata(RuntimeOrigin::signed(1), dao_id.clone(), vec![], vec![]));
+		assert_eq!(Balances::reserved_balance(1), deposit + 4);
+
+		// the full deposit is released on destruction
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(1), dao_id));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(DaoCore::deposit_of(&bounded_dao_id), deposit);
+	});
+}
+
+#[test]
+fn keeps_the_deposit_with_its_depositor_on_ownership_changes() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		let bounded_dao_id: BoundedVec<_, _> = dao_id.clone().try_into().unwrap();
+		let deposit = <Test as Config>::DaoDeposit::get() + 11;
+		assert_ok!(DaoCore::create_dao(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec()
+		));
+
+		// the deposit stays reserved from the creator after a transfer
+		assert_ok!(DaoCore::propose_owner(RuntimeOrigin::signed(1), dao_id.clone(), 2));
+		assert_ok!(DaoCore::accept_ownership(RuntimeOrigin::signed(2), dao_id.clone()));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_eq!(DaoCore::depositor_of(&DaoCore::load_dao(dao_id.clone()).unwrap()), 1);
+
+		// the new owner takes the deposit over with the next update
+		assert_noop!(
+			DaoCore::rename_dao(RuntimeOrigin::signed(2), dao_id.clone(), b"GDAO".to_vec()),
+			BalancesError::<Test>::InsufficientBalance
+		);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 100));
+		assert_ok!(DaoCore::rename_dao(RuntimeOrigin::signed(2), dao_id.clone(), b"GDAO".to_vec()));
+		let deposit = <Test as Config>::DaoDeposit::get() + 4;
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+		assert_eq!(DaoCore::depositor_of(&DaoCore::load_dao(dao_id.clone()).unwrap()), 2);
+
+		// and gets it back on destruction even after handing the DAO on
+		assert_ok!(DaoCore::propose_owner(RuntimeOrigin::signed(2), dao_id.clone(), 1));
+		assert_ok!(DaoCore::accept_ownership(RuntimeOrigin::signed(1), dao_id.clone()));
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(1), dao_id));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 100);
+		assert_eq!(DaoCore::deposit_of(&bounded_dao_id), <Test as Config>::DaoDeposit::get());
+	});
+}
+
+#[test]
+fn can_create_a_dao_with_a_commitment() {
+	new_test_ext().execute_with(|| {
+		let dao_id = b"GDAO".to_vec();
+		let deposit = <Test as Config>::DaoDeposit::get();
+		let commitment = DaoCore::dao_id_commitment(&dao_id, b"salt", &1);
+
+		assert_ok!(DaoCore::commit_dao_id(RuntimeOrigin::signed(1), commitment));
+		System::assert_last_event(Event::DaoIdCommitted { commitment, who: 1 }.into());
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_noop!(
+			DaoCore::commit_dao_id(RuntimeOrigin::signed(2), commitment),
+			Error::<Test>::DaoIdAlreadyCommitted
+		);
+
+		// the commitment needs to age first
+		assert_noop!(
+			DaoCore::create_dao_with_commitment(
+				RuntimeOrigin::signed(1),
+				dao_id.clone(),
+				b"Genesis DAO".to_vec(),
+				b"salt".to_vec(),
+				None
+			),
+			Error::<Test>::DaoIdCommitmentTooRecent
+		);
+		System::set_block_number(System::block_number() + 2);
+
+		// another account or salt does not match the commitment
+		assert_noop!(
+			DaoCore::create_dao_with_commitment(
+				RuntimeOrigin::signed(2),
+				dao_id.clone(),
+				b"Genesis DAO".to_vec(),
+				b"salt".to_vec(),
+				None
+			),
+			Error::<Test>::NoDaoIdCommitment
+		);
+		assert_noop!(
+			DaoCore::create_dao_with_commitment(
+				RuntimeOrigin::signed(1),
+				dao_id.clone(),
+				b"Genesis DAO".to_vec(),
+				b"pepper".to_vec(),
+				None
+			),
+			Error::<Test>::NoDaoIdCommitment
+		);
+
+		assert_ok!(DaoCore::create_dao_with_commitment(
+			RuntimeOrigin::signed(1),
+			dao_id.clone(),
+			b"Genesis DAO".to_vec(),
+			b"salt".to_vec(),
+			None
+		));
+		assert!(DaoCore::get_dao_id_commitment(commitment).is_none());
+		assert_eq!(Balances::reserved_balance(1), deposit + 11);
+
+		// a commitment can be cancelled by the committer only
+		let commitment = DaoCore::dao_id_commitment(b"SDAO", b"salt", &1);
+		assert_ok!(DaoCore::commit_dao_id(RuntimeOrigin::signed(1), commitment));
+		assert_noop!(
+			DaoCore::cancel_dao_id_commitment(RuntimeOrigin::signed(2), commitment),
+			Error::<Test>::NoDaoIdCommitment
+		);
+		assert_ok!(DaoCore::cancel_dao_id_commitment(RuntimeOrigin::signed(1), commitment));
+		System::assert_last_event(Event::DaoIdCommitmentCancelled { commitment }.into());
+		assert_eq!(Balances::reserved_balance(1), deposit + 11);
+
+		// ... unless it has expired, it can no longer be revealed then
+		assert_ok!(DaoCore::commit_dao_id(RuntimeOrigin::signed(1), commitment));
+		System::set_block_number(System::block_number() + 100);
+		assert_noop!(
+			DaoCore::create_dao_with_commitment(
+				RuntimeOrigin::signed(1),
+				b"SDAO".to_vec(),
+				b"Spin-off DAO".to_vec(),
+				b"salt".to_vec(),
+				None
+			),
+			Error::<Test>::DaoIdCommitmentExpired
+		);
+		assert_ok!(DaoCore::cancel_dao_id_commitment(RuntimeOrigin::signed(2), commitment));
+		assert!(DaoCore::get_dao_id_commitment(commitment).is_none());
+		assert_eq!(Balances::reserved_balance(1), deposit + 11);
+	});
+}
+
+#[test]
+fn lists_daos_by_owner() {
+	new_test_ext().execute_with(|| {
+		for dao_id in [b"ADAO", b"BDAO", b"CDAO"] {
+			assert_ok!(DaoCore::create_dao(
+				RuntimeOrigin::signed(1),
+				dao_id.to_vec(),
+				b"Genesis DAO".to_vec()
+			));
+		}
+		assert_ok!(DaoCore::force_change_owner(RuntimeOrigin::root(), b"BDAO".to_vec(), 2));
+
+		let ids = |daos: Vec<DaoOf<Test>>| daos.into_iter().map(|dao| dao.id.to_vec());
+		let mut owned: Vec<_> = ids(DaoCore::daos_by_owner(1, None, 10)).collect();
+		owned.sort();
+		assert_eq!(owned, vec![b"ADAO".to_vec(), b"CDAO".to_vec()]);
+		assert_eq!(
+			ids(DaoCore::daos_by_owner(2, None, 10)).collect::<Vec<_>>(),
+			vec![b"BDAO".to_vec()]
+		);
+
+		// pages continue after the last DAO of the previous page
+		let first: Vec<_> = ids(DaoCore::daos(None, 2)).collect();
+		assert_eq!(first.len(), 2);
+		let rest: Vec<_> = ids(DaoCore::daos(first.last().cloned(), 2)).collect();
+		assert_eq!(rest.len(), 1);
+		assert!(!first.contains(&rest[0]));
+		let first: Vec<_> = ids(DaoCore::daos_by_owner(1, None, 1)).collect();
+		let rest: Vec<_> = ids(DaoCore::daos_by_owner(1, first.last().cloned(), 1)).collect();
+		assert_eq!(rest.len(), 1);
+		assert_ne!(first, rest);
+
+		// the index follows the DAOs
+		assert_ok!(DaoCore::destroy_dao(RuntimeOrigin::signed(2), b"BDAO".to_vec()));
+		assert!(DaoCore::daos_by_owner(2, None, 10).is_empty());
+	});
+}
+
+#[test]
+fn migrates_daos_to_v2() {
+	new_test_ext().execute_with(|| {
+		let dao_id: DaoIdOf<Test> = b"GDAO".to_vec().try_into().unwrap();
+		let old = OldDao {
+			id: dao_id.clone(),
+			owner: 1,
+			name: b"Genesis DAO".to_vec().try_into().unwrap(),
+			asset_id: Some(7),
+			meta: Default::default(),
+			meta_hash: Default::default(),
+		};
+		unhashed::put(&Daos::<Test>::hashed_key_for(&dao_id), &old);
+		StorageVersion::new(1).put::<DaoCore>();
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(DaoCore::on_chain_storage_version(), 2);
+		let dao = DaoCore::get_dao(&dao_id).unwrap();
+		assert_eq!(dao.owner, 1);
+		assert_eq!(dao.asset_id, Some(7));
+		assert_eq!(dao.max_supply, None);
+		assert_eq!(dao.parent, None);
+		assert_eq!(DaoCore::get_asset_dao(7), Some(dao_id.clone()));
+		assert_eq!(DaoCore::daos_by_owner(1, None, 10), vec![dao.clone()]);
+
+		// the migration only runs once
+		MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(DaoCore::get_dao(&dao_id), Some(dao));
 	});
 }
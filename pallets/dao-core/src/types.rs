@@ -5,7 +5,9 @@ use frame_support::{
 	traits::{ConstU32, Currency},
 	BoundedVec, RuntimeDebug,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
+use sp_std::prelude::*;
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type CurrencyOf<T> = <T as Config>::Currency;
@@ -13,9 +15,14 @@ pub type DepositBalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Bala
 pub type AssetIdOf<T> = <T as Config>::AssetId;
 pub type DaoNameOf<T> = BoundedVec<u8, <T as Config>::MaxLengthName>;
 pub type MetadataOf<T> = BoundedVec<u8, <T as Config>::MaxLengthMetadata>;
-pub type DaoOf<T> = Dao<DaoIdOf<T>, AccountIdOf<T>, DaoNameOf<T>, AssetIdOf<T>, MetadataOf<T>>;
+pub type DaoOf<T> =
+	Dao<DaoIdOf<T>, AccountIdOf<T>, DaoNameOf<T>, AssetIdOf<T>, MetadataOf<T>, BalanceOf<T>>;
 pub type DaoIdOf<T> = BoundedVec<u8, <T as Config>::MaxLengthId>;
 pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
+pub type PendingOwnerOf<T> = PendingOwner<AccountIdOf<T>, BlockNumberFor<T>>;
+pub type AllocationOf<T> = Allocation<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+pub type VestingOf<T> = Vesting<BalanceOf<T>, BlockNumberFor<T>>;
+pub type MetadataRecordOf<T> = MetadataRecord<MetadataOf<T>, BlockNumberFor<T>>;
 
 /// The DAO model
 ///
@@ -25,12 +32,91 @@ pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 /// - `asset_id`: Identifier of the issued token (optional, as token may be issued later)
 /// - `meta` : HTTP or IPFS address for the metadata about this DAO (description, logo)
 /// - `meta_hash` : SHA3 hash of the metadata to be found via `meta`
+/// - `max_supply`: Cap on the supply of the DAO token (optional, uncapped if not set)
+/// - `parent`: Identifier of the parent DAO (optional, only set for sub-DAOs)
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub struct Dao<DaoId, AccountId, DaoName, AssetId, Metadata> {
+pub struct Dao<DaoId, AccountId, DaoName, AssetId, Metadata, Balance> {
 	pub id: DaoId,
 	pub owner: AccountId,
 	pub name: DaoName,
 	pub asset_id: Option<AssetId>,
 	pub meta: Metadata,
 	pub meta_hash: BoundedVec<u8, ConstU32<64>>,
+	pub max_supply: Option<Balance>,
+	pub parent: Option<DaoId>,
+}
+
+/// A proposed ownership transfer, waiting to be accepted by the new owner
+///
+/// - `new_owner`: AccountId of the proposed owner
+/// - `expires_at`: Last block in which the transfer can be accepted
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PendingOwner<AccountId, BlockNumber> {
+	pub new_owner: AccountId,
+	pub expires_at: BlockNumber,
+}
+
+/// A linear vesting schedule
+///
+/// - `start`: Block in which the tokens start to vest
+/// - `duration`: Number of blocks after `start` until all tokens have vested
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<BlockNumber> {
+	pub start: BlockNumber,
+	pub duration: u32,
+}
+
+/// A part of the initial token supply of a DAO
+///
+/// - `beneficiary`: AccountId receiving the tokens
+/// - `amount`: Number of tokens allocated
+/// - `vesting`: Optional schedule, the DAO account holds the tokens until they have vested
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Allocation<AccountId, Balance, BlockNumber> {
+	pub beneficiary: AccountId,
+	pub amount: Balance,
+	pub vesting: Option<VestingSchedule<BlockNumber>>,
+}
+
+/// The parameters of a DAO token
+///
+/// - `symbol`: Symbol of the token
+/// - `decimals`: Number of decimals of the token
+/// - `min_balance`: Minimum balance of an account holding the token
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct TokenParameters<Balance> {
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+	pub min_balance: Balance,
+}
+
+/// The recipient of the initial token supply that has not been allocated
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RemainderDestination {
+	Owner,
+	DaoAccount,
+}
+
+/// Tokens vesting for a beneficiary
+///
+/// - `amount`: Number of tokens vesting
+/// - `claimed`: Number of tokens already claimed
+/// - `schedule`: The schedule the tokens vest with
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Vesting<Balance, BlockNumber> {
+	pub amount: Balance,
+	pub claimed: Balance,
+	pub schedule: VestingSchedule<BlockNumber>,
+}
+
+/// Metadata of a DAO as it was set at a block
+///
+/// - `meta`: HTTP or IPFS address for the metadata about the DAO
+/// - `meta_hash`: SHA3 hash of the metadata to be found via `meta`
+/// - `set_at`: Block in which the metadata was set
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct MetadataRecord<Metadata, BlockNumber> {
+	pub meta: Metadata,
+	pub meta_hash: BoundedVec<u8, ConstU32<64>>,
+	pub set_at: BlockNumber,
 }
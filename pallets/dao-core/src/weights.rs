@@ -58,77 +58,639 @@ pub trait WeightInfo {
 	fn issue_token() -> Weight;
 	#[rustfmt::skip]
 	fn set_metadata() -> Weight;
+	#[rustfmt::skip]
+	fn propose_owner() -> Weight;
+	#[rustfmt::skip]
+	fn accept_ownership() -> Weight;
+	#[rustfmt::skip]
+	fn cancel_ownership_transfer() -> Weight;
+	#[rustfmt::skip]
+	fn dissolve_dao() -> Weight;
+	#[rustfmt::skip]
+	fn dissolve_dao_step() -> Weight;
+	#[rustfmt::skip]
+	fn issue_token_with_distribution(n: u32, ) -> Weight;
+	#[rustfmt::skip]
+	fn claim_vested() -> Weight;
+	#[rustfmt::skip]
+	fn mint() -> Weight;
+	#[rustfmt::skip]
+	fn burn() -> Weight;
+	#[rustfmt::skip]
+	fn set_max_supply() -> Weight;
+	#[rustfmt::skip]
+	fn update_token_metadata() -> Weight;
+	#[rustfmt::skip]
+	fn rename_dao() -> Weight;
+	#[rustfmt::skip]
+	fn offer_token() -> Weight;
+	#[rustfmt::skip]
+	fn attach_token() -> Weight;
+	#[rustfmt::skip]
+	fn create_sub_dao() -> Weight;
+	#[rustfmt::skip]
+	fn enable_membership(n: u32, ) -> Weight;
+	#[rustfmt::skip]
+	fn commit_dao_id() -> Weight;
+	#[rustfmt::skip]
+	fn create_dao_with_commitment() -> Weight;
+	#[rustfmt::skip]
+	fn cancel_dao_id_commitment() -> Weight;
+	#[rustfmt::skip]
+	fn force_change_owner() -> Weight;
+	#[rustfmt::skip]
+	fn force_set_metadata() -> Weight;
+	#[rustfmt::skip]
+	fn force_destroy_dao() -> Weight;
 }
 
 /// Weights for pallet_dao_core using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DaoDeposits (r:0 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
 	#[rustfmt::skip]
 	fn create_dao() -> Weight {
 		Weight::from_parts(167_000_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	// Storage: DaoCore UnvestedTotals (r:0 w:1)
 	#[rustfmt::skip]
 	fn destroy_dao() -> Weight {
 		Weight::from_parts(183_000_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
 	// Storage: DaoCore CurrentAssetId (r:1 w:1)
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:1 w:1)
 	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: DaoCore AssetToDao (r:0 w:1)
 	#[rustfmt::skip]
 	fn issue_token() -> Weight {
-		Weight::from_parts(363_000_000_u64, 0)
+		Weight::from_parts(366_000_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore MetadataHistory (r:1 w:1)
+	// Storage: Hookpoints SpecificCallbacks (r:1 w:0)
+	// Storage: Hookpoints GlobalCallbacks (r:1 w:0)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
 	#[rustfmt::skip]
 	fn set_metadata() -> Weight {
-		Weight::from_parts(161_000_000_u64, 0)
+		Weight::from_parts(174_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore PendingOwners (r:0 w:1)
+	#[rustfmt::skip]
+	fn propose_owner() -> Weight {
+		Weight::from_parts(98_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore PendingOwners (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:2)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(176_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore PendingOwners (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(95_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:1)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets SupplyHistory (r:0 w:1)
+	// Storage: DaoCore PendingOwners (r:0 w:1)
+	#[rustfmt::skip]
+	fn dissolve_dao() -> Weight {
+		Weight::from_parts(189_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:100 w:100)
+	// Storage: Assets AccountHistory (r:0 w:100)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	// Storage: DaoCore UnvestedTotals (r:0 w:1)
+	#[rustfmt::skip]
+	fn dissolve_dao_step() -> Weight {
+		Weight::from_parts(2_410_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(103_u64))
+			.saturating_add(T::DbWeight::get().writes(205_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore CurrentAssetId (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: DaoCore AssetToDao (r:0 w:1)
+	// Storage: DaoCore Vestings (r:100 w:100)
+	// Storage: Assets Account (r:100 w:100)
+	// Storage: DaoCore UnvestedTotals (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	#[rustfmt::skip]
+	fn issue_token_with_distribution(n: u32, ) -> Weight {
+		Weight::from_parts(371_000_000_u64, 0)
+			// Standard Error: 24_000
+			.saturating_add(Weight::from_parts(61_000_000_u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore Vestings (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: DaoCore UnvestedTotals (r:1 w:1)
+	#[rustfmt::skip]
+	fn claim_vested() -> Weight {
+		Weight::from_parts(204_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets SupplyHistory (r:1 w:1)
+	// Storage: Assets AccountHistory (r:1 w:1)
+	#[rustfmt::skip]
+	fn mint() -> Weight {
+		Weight::from_parts(231_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets SupplyHistory (r:1 w:1)
+	// Storage: Assets AccountHistory (r:1 w:1)
+	// Storage: DaoCore UnvestedTotals (r:1 w:0)
+	#[rustfmt::skip]
+	fn burn() -> Weight {
+		Weight::from_parts(226_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:0)
+	#[rustfmt::skip]
+	fn set_max_supply() -> Weight {
+		Weight::from_parts(112_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:1)
+	#[rustfmt::skip]
+	fn update_token_metadata() -> Weight {
+		Weight::from_parts(142_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn rename_dao() -> Weight {
+		Weight::from_parts(104_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore TokenOffers (r:0 w:1)
+	#[rustfmt::skip]
+	fn offer_token() -> Weight {
+		Weight::from_parts(98_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore TokenOffers (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: DaoCore AttachedDaos (r:1 w:1)
+	#[rustfmt::skip]
+	fn attach_token() -> Weight {
+		Weight::from_parts(131_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: DaoCore Dissolving (r:2 w:0)
+	// Storage: DaoCore Daos (r:2 w:1)
+	// Storage: DaoCore Children (r:0 w:1)
+	// Storage: DaoCore DaoDeposits (r:0 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	#[rustfmt::skip]
+	fn create_sub_dao() -> Weight {
+		Weight::from_parts(181_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore Members (r:1 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	#[rustfmt::skip]
+	fn enable_membership(n: u32, ) -> Weight {
+		Weight::from_parts(92_000_000_u64, 0)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(410_000_u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore DaoIdCommitments (r:1 w:1)
+	#[rustfmt::skip]
+	fn commit_dao_id() -> Weight {
+		Weight::from_parts(98_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore DaoIdCommitments (r:1 w:1)
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:2 w:1)
+	// Storage: DaoCore DaoDeposits (r:0 w:1)
+	// Storage: DaoCore Children (r:0 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	#[rustfmt::skip]
+	fn create_dao_with_commitment() -> Weight {
+		Weight::from_parts(196_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: DaoCore DaoIdCommitments (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_dao_id_commitment() -> Weight {
+		Weight::from_parts(94_000_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore PendingOwners (r:0 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:2)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn force_change_owner() -> Weight {
+		Weight::from_parts(126_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore MetadataHistory (r:1 w:1)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn force_set_metadata() -> Weight {
+		Weight::from_parts(139_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:1)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore Children (r:1 w:1)
+	// Storage: DaoCore AttachedDaos (r:1 w:0)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore PendingOwners (r:0 w:1)
+	// Storage: DaoCore MetadataHistory (r:0 w:1)
+	// Storage: DaoCore Members (r:0 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn force_destroy_dao() -> Weight {
+		Weight::from_parts(201_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DaoDeposits (r:0 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
 	#[rustfmt::skip]
 	fn create_dao() -> Weight {
 		Weight::from_parts(167_000_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	// Storage: DaoCore UnvestedTotals (r:0 w:1)
 	#[rustfmt::skip]
 	fn destroy_dao() -> Weight {
 		Weight::from_parts(183_000_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
 	// Storage: DaoCore CurrentAssetId (r:1 w:1)
 	// Storage: Assets Asset (r:1 w:1)
 	// Storage: Assets Account (r:1 w:1)
 	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: DaoCore AssetToDao (r:0 w:1)
 	#[rustfmt::skip]
 	fn issue_token() -> Weight {
-		Weight::from_parts(363_000_000_u64, 0)
+		Weight::from_parts(366_000_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore MetadataHistory (r:1 w:1)
+	// Storage: Hookpoints SpecificCallbacks (r:1 w:0)
+	// Storage: Hookpoints GlobalCallbacks (r:1 w:0)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
 	#[rustfmt::skip]
 	fn set_metadata() -> Weight {
-		Weight::from_parts(161_000_000_u64, 0)
+		Weight::from_parts(174_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore PendingOwners (r:0 w:1)
+	#[rustfmt::skip]
+	fn propose_owner() -> Weight {
+		Weight::from_parts(98_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore PendingOwners (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:2)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn accept_ownership() -> Weight {
+		Weight::from_parts(176_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore PendingOwners (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_ownership_transfer() -> Weight {
+		Weight::from_parts(95_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:1)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets SupplyHistory (r:0 w:1)
+	// Storage: DaoCore PendingOwners (r:0 w:1)
+	#[rustfmt::skip]
+	fn dissolve_dao() -> Weight {
+		Weight::from_parts(189_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:100 w:100)
+	// Storage: Assets AccountHistory (r:0 w:100)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	// Storage: DaoCore UnvestedTotals (r:0 w:1)
+	#[rustfmt::skip]
+	fn dissolve_dao_step() -> Weight {
+		Weight::from_parts(2_410_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(103_u64))
+			.saturating_add(RocksDbWeight::get().writes(205_u64))
+	}
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore CurrentAssetId (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: DaoCore AssetToDao (r:0 w:1)
+	// Storage: DaoCore Vestings (r:100 w:100)
+	// Storage: Assets Account (r:100 w:100)
+	// Storage: DaoCore UnvestedTotals (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	#[rustfmt::skip]
+	fn issue_token_with_distribution(n: u32, ) -> Weight {
+		Weight::from_parts(371_000_000_u64, 0)
+			// Standard Error: 24_000
+			.saturating_add(Weight::from_parts(61_000_000_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore Vestings (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: DaoCore UnvestedTotals (r:1 w:1)
+	#[rustfmt::skip]
+	fn claim_vested() -> Weight {
+		Weight::from_parts(204_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets SupplyHistory (r:1 w:1)
+	// Storage: Assets AccountHistory (r:1 w:1)
+	#[rustfmt::skip]
+	fn mint() -> Weight {
+		Weight::from_parts(231_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets SupplyHistory (r:1 w:1)
+	// Storage: Assets AccountHistory (r:1 w:1)
+	// Storage: DaoCore UnvestedTotals (r:1 w:0)
+	#[rustfmt::skip]
+	fn burn() -> Weight {
+		Weight::from_parts(226_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:0)
+	#[rustfmt::skip]
+	fn set_max_supply() -> Weight {
+		Weight::from_parts(112_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: Assets Metadata (r:1 w:1)
+	#[rustfmt::skip]
+	fn update_token_metadata() -> Weight {
+		Weight::from_parts(142_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn rename_dao() -> Weight {
+		Weight::from_parts(104_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore TokenOffers (r:0 w:1)
+	#[rustfmt::skip]
+	fn offer_token() -> Weight {
+		Weight::from_parts(98_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore TokenOffers (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: DaoCore AttachedDaos (r:1 w:1)
+	#[rustfmt::skip]
+	fn attach_token() -> Weight {
+		Weight::from_parts(131_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: DaoCore Dissolving (r:2 w:0)
+	// Storage: DaoCore Daos (r:2 w:1)
+	// Storage: DaoCore Children (r:0 w:1)
+	// Storage: DaoCore DaoDeposits (r:0 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	#[rustfmt::skip]
+	fn create_sub_dao() -> Weight {
+		Weight::from_parts(181_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:0)
+	// Storage: DaoCore Members (r:1 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	#[rustfmt::skip]
+	fn enable_membership(n: u32, ) -> Weight {
+		Weight::from_parts(92_000_000_u64, 0)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(410_000_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore DaoIdCommitments (r:1 w:1)
+	#[rustfmt::skip]
+	fn commit_dao_id() -> Weight {
+		Weight::from_parts(98_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DaoCore DaoIdCommitments (r:1 w:1)
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:2 w:1)
+	// Storage: DaoCore DaoDeposits (r:0 w:1)
+	// Storage: DaoCore Children (r:0 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	#[rustfmt::skip]
+	fn create_dao_with_commitment() -> Weight {
+		Weight::from_parts(196_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: DaoCore DaoIdCommitments (r:1 w:1)
+	#[rustfmt::skip]
+	fn cancel_dao_id_commitment() -> Weight {
+		Weight::from_parts(94_000_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore PendingOwners (r:0 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:2)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn force_change_owner() -> Weight {
+		Weight::from_parts(126_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:0)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore MetadataHistory (r:1 w:1)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn force_set_metadata() -> Weight {
+		Weight::from_parts(139_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: DaoCore Dissolving (r:1 w:1)
+	// Storage: DaoCore Daos (r:1 w:1)
+	// Storage: DaoCore Children (r:1 w:1)
+	// Storage: DaoCore AttachedDaos (r:1 w:0)
+	// Storage: DaoCore DaoDeposits (r:1 w:1)
+	// Storage: DaoCore PendingOwners (r:0 w:1)
+	// Storage: DaoCore MetadataHistory (r:0 w:1)
+	// Storage: DaoCore Members (r:0 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: DaoCore OwnerDaos (r:0 w:1)
+	// Storage: DaoCore DaoDepositors (r:1 w:1)
+	#[rustfmt::skip]
+	fn force_destroy_dao() -> Weight {
+		Weight::from_parts(201_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
 }
@@ -0,0 +1,64 @@
+//! Runtime API for querying the governance and proposals of DAOs.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::DispatchError;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// Queries of DAOs, their governance and proposals.
+	pub trait DaoApi<
+		AccountId,
+		Balance,
+		BlockNumber,
+		ProposalId,
+		Dao,
+		Governance,
+		Proposal,
+		VotingPowerExplanation,
+	>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		ProposalId: Codec,
+		Dao: Codec,
+		Governance: Codec,
+		Proposal: Codec,
+		VotingPowerExplanation: Codec,
+	{
+		/// A DAO together with its governance, if one has been set.
+		fn get_dao(dao_id: Vec<u8>) -> Option<(Dao, Option<Governance>)>;
+
+		/// The id of the DAO of a proposal or proposal slot.
+		fn get_proposal_dao(proposal_id: ProposalId) -> Option<Vec<u8>>;
+
+		/// The running proposals of a DAO with their current tallies.
+		fn get_active_proposals(dao_id: Vec<u8>) -> Vec<(ProposalId, Proposal)>;
+
+		/// The voting power `voter` would vote with on a proposal, `None` if they cannot vote.
+		fn get_voting_power(proposal_id: ProposalId, voter: AccountId) -> Option<Balance>;
+
+		/// How the voting power of `voter` on a proposal comes about, from their own and delegated
+		/// balance through each vote plugin of the DAO contract. Dry-run without state changes.
+		fn explain_voting_power(
+			proposal_id: ProposalId,
+			voter: AccountId,
+		) -> Option<VotingPowerExplanation>;
+
+		/// Whether a proposal would be accepted if it was finalized now, or the error
+		/// `finalize_proposal` would fail with.
+		fn dry_run_finalize(proposal_id: ProposalId) -> Result<bool, DispatchError>;
+
+		/// The token balance of `who` in a DAO at `block`, `None` if the DAO has no token.
+		fn get_historical_balance(
+			dao_id: Vec<u8>,
+			who: AccountId,
+			block: BlockNumber,
+		) -> Option<Balance>;
+
+		/// The token supply of a DAO at `block`, `None` if the DAO has no token or the block is
+		/// beyond the history horizon.
+		fn get_historical_supply(dao_id: Vec<u8>, block: BlockNumber) -> Option<Balance>;
+	}
+}
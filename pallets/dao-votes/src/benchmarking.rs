@@ -7,8 +7,10 @@
 use super::*;
 use crate::{test_utils::*, Pallet as Votes};
 use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::{traits::Get, weights::Weight};
 use frame_system::{Pallet as System, RawOrigin};
 use pallet_dao_core::{Config as DaoConfig, Currency};
+use sp_core::{crypto::KeyTypeId, sr25519};
 
 /// A whitelisted caller with enough funds
 fn setup_caller<T: Config>() -> T::AccountId {
@@ -43,6 +45,12 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 }
 
 benchmarks! {
+	where_clause {
+		where
+			T::OffchainPublic: From<sr25519::Public>,
+			T::OffchainSignature: From<sr25519::Signature>,
+	}
+
 	create_proposal {
 		let creator = setup_caller::<T>();
 		let dao_id = setup_dao_with_governance::<T>(creator.clone());
@@ -54,6 +62,19 @@ benchmarks! {
 			proposal_id: Votes::<T>::get_current_proposal_id() }.into());
 	}
 
+	create_proposal_of_kind {
+		let creator = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(creator.clone());
+		let data: BoundedVec<_, _> = sp_std::vec![0_u8; T::MaxCallDataLength::get() as usize].try_into().expect("fits");
+		let kind = ProposalKind::ContractCall { contract: creator.clone(), selector: [0_u8; 4], data };
+	}: _(RawOrigin::Signed(creator.clone()), dao_id.clone(), kind)
+	verify {
+		assert_last_event::<T>(Event::ProposalCreated {
+			dao_id: dao_id.try_into().unwrap(),
+			creator,
+			proposal_id: Votes::<T>::get_current_proposal_id() }.into());
+	}
+
 	set_metadata {
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao_with_governance::<T>(caller.clone());
@@ -109,6 +130,18 @@ benchmarks! {
 		assert_last_event::<T>(Event::SetGovernanceMajorityVote { dao_id, proposal_duration, proposal_token_deposit, minimum_majority_per_1024 }.into());
 	}
 
+	set_governance_optimistic_vote {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let proposal_duration = 1_u32;
+		let proposal_token_deposit = 1_u32.into();
+		let objection_threshold_per_1024 = 10;
+	}: _(RawOrigin::Signed(caller.clone()), dao_id.clone(), proposal_duration, proposal_token_deposit, objection_threshold_per_1024)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::SetGovernanceOptimisticVote { dao_id, proposal_duration, proposal_token_deposit, objection_threshold_per_1024 }.into());
+	}
+
 	mark_implemented {
 		let caller = setup_caller::<T>();
 		let dao_id = setup_dao_with_governance_and_no_duration::<T>(caller.clone());
@@ -118,5 +151,110 @@ benchmarks! {
 		assert_last_event::<T>(Event::ProposalImplemented { proposal_id }.into());
 	}
 
+	submit_signed_votes {
+		let n in 1 .. T::MaxSignedVotes::get();
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let proposal_id = setup_proposal::<T>(caller.clone(), dao_id);
+		let key_type = KeyTypeId(*b"vote");
+		let mut votes = Vec::new();
+		for _ in 0 .. n {
+			let public = sp_io::crypto::sr25519_generate(key_type, None);
+			let payload = Votes::<T>::signed_vote_payload(proposal_id, Some(true), 0);
+			let signature = sp_io::crypto::sr25519_sign(key_type, &public, &payload).expect("key exists");
+			votes.push(SignedVote {
+				proposal_id,
+				voter: T::OffchainPublic::from(public).into_account(),
+				in_favor: Some(true),
+				nonce: 0,
+				signature: signature.into(),
+			});
+		}
+		let voter = votes[n as usize - 1].voter.clone();
+		let votes: BoundedVec<_, _> = votes.try_into().expect("within bounds");
+	}: _(RawOrigin::Signed(caller), votes)
+	verify {
+		assert_eq!(Votes::<T>::vote_nonce(&voter), 1);
+		assert_last_event::<T>(Event::VoteCast { proposal_id, voter, in_favor: Some(true) }.into());
+	}
+
+	set_commit_reveal {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao::<T>(caller.clone());
+		let reveal_duration = Some(10_u32);
+	}: _(RawOrigin::Signed(caller), dao_id.clone(), reveal_duration)
+	verify {
+		let dao_id: BoundedVec<_, _> = dao_id.try_into().expect("fits");
+		assert_last_event::<T>(Event::SetCommitReveal { dao_id, reveal_duration }.into());
+	}
+
+	commit_vote {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		setup_commit_reveal::<T>(caller.clone(), dao_id.clone(), 10);
+		let proposal_id = setup_proposal::<T>(caller.clone(), dao_id);
+		let commitment = Votes::<T>::vote_commitment(proposal_id, &caller, true, [0_u8; 32]);
+	}: _(RawOrigin::Signed(caller.clone()), proposal_id, commitment)
+	verify {
+		assert_last_event::<T>(Event::VoteCommitted { proposal_id, voter: caller }.into());
+	}
+
+	reveal_vote {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance_and_no_duration::<T>(caller.clone());
+		setup_commit_reveal::<T>(caller.clone(), dao_id.clone(), 10);
+		let proposal_id = setup_proposal::<T>(caller.clone(), dao_id);
+		let salt = [0_u8; 32];
+		let commitment = Votes::<T>::vote_commitment(proposal_id, &caller, true, salt);
+		assert_eq!(
+			Votes::<T>::commit_vote(RawOrigin::Signed(caller.clone()).into(), proposal_id, commitment),
+			Ok(())
+		);
+		run_to_block::<T>(System::<T>::block_number() + 1_u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), proposal_id, true, salt)
+	verify {
+		assert_last_event::<T>(Event::VoteCast { proposal_id, voter: caller, in_favor: Some(true) }.into());
+	}
+
+	create_proposal_with_metadata {
+		let creator = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(creator.clone());
+		let metadata = b"http://my.cool.proposal".to_vec();
+		// https://en.wikipedia.org/wiki/SHA-3#Examples_of_SHA-3_variants
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+	}: _(RawOrigin::Signed(creator), dao_id, ProposalKind::Text, metadata, hash)
+	verify {
+		assert_last_event::<T>(Event::ProposalMetadataSet { proposal_id: Votes::<T>::get_current_proposal_id() }.into());
+	}
+
+	remove_vote_commitment {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance_and_no_duration::<T>(caller.clone());
+		setup_commit_reveal::<T>(caller.clone(), dao_id.clone(), 1);
+		let proposal_id = setup_proposal::<T>(caller.clone(), dao_id);
+		let commitment = Votes::<T>::vote_commitment(proposal_id, &caller, true, [0_u8; 32]);
+		assert_eq!(
+			Votes::<T>::commit_vote(RawOrigin::Signed(caller.clone()).into(), proposal_id, commitment),
+			Ok(())
+		);
+		run_to_block::<T>(System::<T>::block_number() + 2_u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), proposal_id)
+	verify {
+		assert_last_event::<T>(Event::VoteCommitmentRemoved { proposal_id, voter: caller }.into());
+	}
+
+	expire_proposal_slot {
+		let caller = setup_caller::<T>();
+		let dao_id = setup_dao_with_governance::<T>(caller.clone());
+		let proposal_id = create_proposal_id::<T>(caller, dao_id);
+		let now = System::<T>::block_number() + T::ProposalSlotExpiry::get().into() + 1_u32.into();
+		NextSlotExpiry::<T>::put(now);
+	}: {
+		Votes::<T>::expire_proposal_slots(now, Weight::MAX);
+	}
+	verify {
+		assert_last_event::<T>(Event::ProposalSlotExpired { proposal_id }.into());
+	}
+
 	impl_benchmark_test_suite!(Votes, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -1,34 +1,599 @@
 use crate::{
-	pallet::{Governances, Proposals},
-	Config, Pallet, ProposalOf, ProposalStatus,
+	hooks::{explain_on_vote, on_proposal_create, on_proposal_metadata, on_vote},
+	pallet::{
+		CurrentProposalId, DaoProposals, Governances, NextSlotExpiry, ProposalIndexCursor,
+		ProposalKinds, ProposalRevealDurations, ProposalSlots, ProposalTokenDeposits, Proposals,
+		RevealDurations, SlotExpiries, VoteCommitments, Votes,
+	},
+	weights::WeightInfo,
+	Config, Error, Event, GovernanceOf, Pallet, Proposal, ProposalKind, ProposalKindOf, ProposalOf,
+	ProposalSlot, ProposalSlotOf, ProposalStatus, Voting, VotingPowerExplanation,
+	VotingPowerExplanationOf, SIGNED_VOTE_TAG,
+};
+use codec::Encode;
+use commons::traits::pallets::{ActiveProposals, AssetInterface, DaoCleanup};
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	sp_runtime::{
+		traits::{Hash, One, Saturating, Zero},
+		TransactionOutcome,
+	},
+	storage::with_transaction,
+	traits::{Get, ReservableCurrency},
+	weights::Weight,
+	BoundedVec,
 };
-use commons::traits::pallets::ActiveProposals;
-use frame_support::traits::Get;
 use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_dao_core::{
+	AccountIdOf, BalanceOf, CurrencyOf, DaoIdOf, DaoOf, Error as DaoError, Pallet as Core,
+};
 use sp_std::prelude::*;
 
 impl<T: Config> Pallet<T> {
+	/// Create a proposal slot of the given kind, reserving the proposal deposits of `creator`.
+	pub(crate) fn do_create_proposal(
+		creator: AccountIdOf<T>,
+		dao_id: Vec<u8>,
+		kind: ProposalKindOf<T>,
+	) -> Result<T::ProposalId, DispatchError> {
+		let dao = Core::<T>::load_dao(dao_id)?;
+		let dao_id = dao.id;
+		// members of membership DAOs propose without a token deposit
+		let asset_id = if Core::<T>::is_membership_dao(&dao_id) {
+			ensure!(Core::<T>::is_member(&dao_id, &creator), Error::<T>::NotAMember);
+			None
+		} else {
+			Some(dao.asset_id.ok_or(Error::<T>::DaoTokenNotYetIssued)?)
+		};
+		let governance =
+			<Governances<T>>::get(dao_id.clone()).ok_or(Error::<T>::GovernanceNotSet)?;
+
+		match &kind {
+			ProposalKind::GovernanceChange { voting, .. } => {
+				Self::ensure_voting_is_valid(voting)?;
+			},
+			// a DAO can only change the governance of its own sub-DAOs
+			ProposalKind::ChildGovernanceChange { child, voting, .. } => {
+				ensure!(Core::<T>::is_child_of(child, &dao_id), Error::<T>::NotASubDao);
+				Self::ensure_voting_is_valid(voting)?;
+			},
+			// members can only be changed in membership DAOs
+			ProposalKind::MembershipChange { .. } => {
+				ensure!(asset_id.is_none(), DaoError::<T>::MembershipNotEnabled);
+			},
+			_ => {},
+		}
+
+		// let the DAO validate the payload
+		ensure!(
+			on_proposal_create::<T>(dao.owner, creator.clone(), creator.clone(), kind.encode()),
+			Error::<T>::ProposalKindRejected
+		);
+
+		let deposit = <T as Config>::ProposalDeposit::get();
+
+		// reserve currency
+		CurrencyOf::<T>::reserve(&creator, deposit)?;
+
+		// reserve DAO token, but unreserve currency if that fails
+		let token_deposit = match asset_id {
+			Some(asset_id) => {
+				if let Err(error) =
+					T::ExposeAsset::reserve(asset_id, &creator, governance.proposal_token_deposit)
+				{
+					CurrencyOf::<T>::unreserve(&creator, deposit);
+					Err(error)?;
+				};
+				governance.proposal_token_deposit
+			},
+			None => Zero::zero(),
+		};
+		// increase proposal counter
+		let proposal_id = <CurrentProposalId<T>>::mutate(|id| {
+			id.saturating_inc();
+			*id
+		});
+
+		// store a proposal slot
+		let slot = ProposalSlot {
+			dao_id: dao_id.clone(),
+			creator: creator.clone(),
+			created_at: <frame_system::Pallet<T>>::block_number(),
+			token_deposit,
+		};
+		<SlotExpiries<T>>::insert(Self::slot_expiry(&slot), proposal_id, ());
+		<DaoProposals<T>>::insert(&dao_id, proposal_id, ());
+		<ProposalSlots<T>>::insert(proposal_id, slot);
+		<ProposalKinds<T>>::insert(proposal_id, kind);
+		// emit an event
+		Self::deposit_event(Event::<T>::ProposalCreated { dao_id, creator, proposal_id });
+
+		Ok(proposal_id)
+	}
+
+	/// Ensure an objection threshold does not exceed the whole voting power, a proposal could
+	/// never be objected to otherwise.
+	///
+	/// - `voting`: the voting of a governance
+	pub(crate) fn ensure_voting_is_valid(voting: &Voting) -> DispatchResult {
+		if let Voting::Optimistic { objection_threshold_per_1024 } = voting {
+			ensure!(*objection_threshold_per_1024 <= 1024, Error::<T>::ObjectionThresholdTooHigh);
+		}
+		Ok(())
+	}
+
+	/// Apply the payload of an accepted proposal of `dao_id` that changes on-chain state.
+	///
+	/// A `ChildGovernanceChange` replaces the governance of the sub-DAO, unless it has left the
+	/// DAO since. A `MembershipChange` adds or removes the member, if that is no longer possible
+	/// `MembershipChangeFailed` is emitted with the reason. Other kinds of proposals are left to be
+	/// implemented by the DAO.
+	pub(crate) fn apply_proposal_kind(proposal_id: T::ProposalId, dao_id: &DaoIdOf<T>) {
+		match <ProposalKinds<T>>::get(proposal_id) {
+			Some(ProposalKind::ChildGovernanceChange {
+				child,
+				proposal_duration,
+				proposal_token_deposit,
+				voting,
+			}) => {
+				if !Core::<T>::is_child_of(&child, dao_id) {
+					return
+				}
+				let gov = GovernanceOf::<T> { proposal_duration, proposal_token_deposit, voting };
+				<Governances<T>>::insert(&child, gov);
+				Self::deposit_event(Event::<T>::SubDaoGovernanceChanged {
+					dao_id: child,
+					proposal_id,
+				});
+			},
+			Some(ProposalKind::MembershipChange { member, add }) => {
+				let result = if add {
+					Core::<T>::add_member(dao_id, member)
+				} else {
+					Core::<T>::remove_member(dao_id, member)
+				};
+				if let Err(error) = result {
+					Self::deposit_event(Event::<T>::MembershipChangeFailed { proposal_id, error });
+				}
+			},
+			_ => {},
+		}
+	}
+
+	/// The share `per_1024` of the voting power in a DAO for a proposal born in `birth_block`.
+	///
+	/// The voting power is the number of members for membership DAOs and the token supply at
+	/// `birth_block` otherwise.
+	pub(crate) fn voting_power_share(
+		dao: &DaoOf<T>,
+		birth_block: BlockNumberFor<T>,
+		per_1024: u16,
+	) -> BalanceOf<T> {
+		if Core::<T>::is_membership_dao(&dao.id) {
+			let members = Core::<T>::member_count(&dao.id);
+			return (members.saturating_mul(per_1024.into()) / 1024).into()
+		}
+		let asset_id = dao.asset_id.expect("asset has been issued");
+		let token_supply = T::ExposeAsset::total_historical_supply(asset_id, birth_block)
+			.expect("History exists (horizon checked above)");
+		token_supply / Into::<BalanceOf<T>>::into(1024_u32) * per_1024.into()
+	}
+
+	/// Turn the proposal slot `proposal_id` into a running proposal with the given metadata.
+	pub(crate) fn do_set_metadata(
+		sender: AccountIdOf<T>,
+		proposal_id: T::ProposalId,
+		meta: Vec<u8>,
+		hash: Vec<u8>,
+	) -> DispatchResult {
+		let slot = <ProposalSlots<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+		ensure!(sender == slot.creator, Error::<T>::SenderIsNotProposalCreator);
+
+		let meta: BoundedVec<_, _> =
+			meta.try_into().map_err(|_| DaoError::<T>::MetadataInvalidLengthTooLong)?;
+		let hash: BoundedVec<_, _> =
+			hash.try_into().map_err(|_| DaoError::<T>::HashInvalidWrongLength)?;
+
+		// let the DAO validate the proposal together with its metadata
+		let dao = Core::<T>::get_dao(&slot.dao_id).ok_or(DaoError::<T>::DaoDoesNotExist)?;
+		let payload = <ProposalKinds<T>>::get(proposal_id).map(|kind| kind.encode());
+		ensure!(
+			on_proposal_metadata::<T>(
+				dao.owner,
+				sender.clone(),
+				sender.clone(),
+				payload.unwrap_or_default(),
+				meta.to_vec(),
+				hash.to_vec(),
+			),
+			Error::<T>::ProposalMetadataRejected
+		);
+
+		let birth_block = <frame_system::Pallet<T>>::block_number();
+		// the proposal keeps the commit-reveal mode of its DAO at this point
+		if let Some(reveal_duration) = <RevealDurations<T>>::get(&slot.dao_id) {
+			<ProposalRevealDurations<T>>::insert(proposal_id, reveal_duration);
+		}
+		// store the proposal
+		<ProposalSlots<T>>::remove(proposal_id);
+		<SlotExpiries<T>>::remove(Self::slot_expiry(&slot), proposal_id);
+		if !slot.token_deposit.is_zero() {
+			<ProposalTokenDeposits<T>>::insert(proposal_id, slot.token_deposit);
+		}
+		<Proposals<T>>::insert(
+			proposal_id,
+			Proposal {
+				dao_id: slot.dao_id,
+				creator: sender,
+				birth_block,
+				status: ProposalStatus::Running,
+				in_favor: Zero::zero(),
+				against: Zero::zero(),
+				meta,
+				meta_hash: hash,
+			},
+		);
+
+		// emit an event
+		Self::deposit_event(Event::<T>::ProposalMetadataSet { proposal_id });
+		Ok(())
+	}
+
+	/// The block in which a proposal slot expires, unless it received metadata.
+	pub(crate) fn slot_expiry(slot: &ProposalSlotOf<T>) -> BlockNumberFor<T> {
+		let expiry: BlockNumberFor<T> = T::ProposalSlotExpiry::get().into();
+		slot.created_at.saturating_add(expiry).saturating_add(One::one())
+	}
+
+	/// Remove proposal slots that did not receive metadata in time and refund their deposits.
+	///
+	/// The slots are processed in the order of their expiry, continuing where the previous
+	/// call stopped. Returns the weight consumed, which never exceeds `remaining_weight`.
+	pub(crate) fn expire_proposal_slots(
+		now: BlockNumberFor<T>,
+		remaining_weight: Weight,
+	) -> Weight {
+		let read = T::DbWeight::get().reads(1);
+		let write = T::DbWeight::get().writes(1);
+		let expire = <T as Config>::WeightInfo::expire_proposal_slot();
+
+		// reading and updating the cursor
+		let mut consumed = read.saturating_add(write);
+		if !remaining_weight.all_gte(consumed) {
+			return Weight::zero()
+		}
+		let mut block = <NextSlotExpiry<T>>::get();
+		while block <= now {
+			if !remaining_weight.all_gte(consumed.saturating_add(read).saturating_add(expire)) {
+				break
+			}
+			consumed.saturating_accrue(read);
+			let proposal_id = match <SlotExpiries<T>>::iter_key_prefix(block).next() {
+				Some(proposal_id) => proposal_id,
+				None => {
+					block.saturating_inc();
+					continue
+				},
+			};
+			consumed.saturating_accrue(expire);
+			<SlotExpiries<T>>::remove(block, proposal_id);
+			// slots that received metadata in the meantime are gone
+			let slot = match <ProposalSlots<T>>::take(proposal_id) {
+				Some(slot) => slot,
+				None => continue,
+			};
+			<ProposalKinds<T>>::remove(proposal_id);
+			<DaoProposals<T>>::remove(&slot.dao_id, proposal_id);
+
+			// refund the deposits, the DAO token may have been destroyed in the meantime
+			CurrencyOf::<T>::unreserve(&slot.creator, <T as Config>::ProposalDeposit::get());
+			if let Some(asset_id) = Core::<T>::get_dao(&slot.dao_id).and_then(|dao| dao.asset_id) {
+				let _ = T::ExposeAsset::unreserve(asset_id, &slot.creator, slot.token_deposit);
+			}
+
+			Self::deposit_event(Event::<T>::ProposalSlotExpired { proposal_id });
+		}
+		<NextSlotExpiry<T>>::put(block);
+
+		consumed
+	}
+
+	/// The payload a voter signs for a vote submitted by a relayer.
+	///
+	/// The payload is bound to this pallet and chain by the `SIGNED_VOTE_TAG` and the genesis
+	/// hash.
+	pub fn signed_vote_payload(
+		proposal_id: T::ProposalId,
+		in_favor: Option<bool>,
+		nonce: u32,
+	) -> Vec<u8> {
+		let genesis_hash = <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+		(SIGNED_VOTE_TAG, genesis_hash, proposal_id, in_favor, nonce).encode()
+	}
+
+	/// Record a vote of `voter` on a running proposal and update the proposal's tallies.
+	pub(crate) fn do_vote(
+		voter: AccountIdOf<T>,
+		proposal_id: T::ProposalId,
+		in_favor: Option<bool>,
+	) -> DispatchResult {
+		// check that a proposal exists with the given id
+		let proposal = <Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+		// check that the proposal is running
+		ensure!(proposal.status == ProposalStatus::Running, Error::<T>::ProposalStatusNotRunning);
+
+		// votes on proposals in commit-reveal mode are only counted once revealed
+		ensure!(
+			!<ProposalRevealDurations<T>>::contains_key(proposal_id),
+			Error::<T>::VoteMustBeCommitted
+		);
+
+		let governance =
+			<Governances<T>>::get(&proposal.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
+
+		// check that the proposal has not yet run for its entire duration
+		ensure!(
+			<frame_system::Pallet<T>>::block_number() - proposal.birth_block <=
+				governance.proposal_duration.into(),
+			Error::<T>::ProposalDurationHasPassed
+		);
+
+		Self::count_vote(voter, proposal_id, proposal, in_favor)
+	}
+
+	/// The commitment to a vote of `voter` on a proposal in commit-reveal mode.
+	///
+	/// - `proposal_id`: the proposal to vote on
+	/// - `voter`: the account committing the vote
+	/// - `in_favor`: the vote
+	/// - `salt`: a secret only revealed together with the vote
+	pub fn vote_commitment(
+		proposal_id: T::ProposalId,
+		voter: &AccountIdOf<T>,
+		in_favor: bool,
+		salt: [u8; 32],
+	) -> <T as frame_system::Config>::Hash {
+		T::Hashing::hash_of(&(proposal_id, voter, in_favor, salt))
+	}
+
+	/// Count the vote of `voter` towards the tallies of `proposal`, replacing an earlier vote.
+	pub(crate) fn count_vote(
+		voter: AccountIdOf<T>,
+		proposal_id: T::ProposalId,
+		mut proposal: ProposalOf<T>,
+		in_favor: Option<bool>,
+	) -> DispatchResult {
+		let vote = <Votes<T>>::get(proposal_id, &voter);
+		if vote == in_favor {
+			// vote already stored
+			return Ok(())
+		}
+
+		let dao = Core::<T>::get_dao(&proposal.dao_id).expect("DAO exists");
+		let voting_power = Self::base_voting_power(&dao, &voter, proposal.birth_block)?;
+		<Votes<T>>::set(proposal_id, &voter, in_favor);
+
+		let voting_power =
+			on_vote::<T>(dao.owner.clone(), voter.clone(), voter.clone(), voting_power);
+		// undo old vote
+		match vote {
+			Some(true) => {
+				proposal.in_favor -= voting_power;
+			},
+			Some(false) => {
+				proposal.against -= voting_power;
+			},
+			None => {},
+		}
+		// count new vote
+		match in_favor {
+			Some(true) => {
+				proposal.in_favor += voting_power;
+			},
+			Some(false) => {
+				proposal.against += voting_power;
+			},
+			None => {},
+		}
+		// record updated proposal counts
+		<Proposals<T>>::insert(proposal_id, proposal);
+
+		Self::deposit_event(Event::<T>::VoteCast { proposal_id, voter, in_favor });
+		Ok(())
+	}
+
+	/// The voting power of `voter` on proposals of `dao` created at `birth_block`, before it is
+	/// adjusted by the `on_vote` hook.
+	///
+	/// Members of membership DAOs have one vote each, token holders their balance at
+	/// `birth_block`.
+	pub(crate) fn base_voting_power(
+		dao: &DaoOf<T>,
+		voter: &AccountIdOf<T>,
+		birth_block: BlockNumberFor<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if Core::<T>::is_membership_dao(&dao.id) {
+			ensure!(Core::<T>::is_member(&dao.id, voter), Error::<T>::NotAMember);
+			return Ok(1_u32.into())
+		}
+		let asset_id = dao.asset_id.expect("asset has been issued");
+		Ok(T::ExposeAsset::total_historical_balance(asset_id, voter, birth_block))
+	}
+
+	/// The voting power `voter` would vote with on a proposal, including the `on_vote` hook.
+	///
+	/// Returns `None` if the proposal does not exist or `voter` cannot vote on it.
+	pub fn voting_power(proposal_id: T::ProposalId, voter: AccountIdOf<T>) -> Option<BalanceOf<T>> {
+		let proposal = <Proposals<T>>::get(proposal_id)?;
+		let dao = Core::<T>::get_dao(&proposal.dao_id)?;
+		let voting_power = Self::base_voting_power(&dao, &voter, proposal.birth_block).ok()?;
+		Some(on_vote::<T>(dao.owner, voter.clone(), voter, voting_power))
+	}
+
+	/// How the voting power `voter` would vote with on a proposal comes about: the own and
+	/// delegated token balance at the birth block and the voting power after each vote plugin of
+	/// the DAO contract.
+	///
+	/// The plugins are executed in a transaction that is rolled back, so this never changes any
+	/// state. Returns `None` if the proposal does not exist or `voter` cannot vote on it.
+	pub fn explain_voting_power(
+		proposal_id: T::ProposalId,
+		voter: AccountIdOf<T>,
+	) -> Option<VotingPowerExplanationOf<T>> {
+		let proposal = <Proposals<T>>::get(proposal_id)?;
+		let dao = Core::<T>::get_dao(&proposal.dao_id)?;
+		let total = Self::base_voting_power(&dao, &voter, proposal.birth_block).ok()?;
+		let delegated = match dao.asset_id {
+			Some(asset_id) if !Core::<T>::is_membership_dao(&dao.id) =>
+				T::ExposeAsset::total_historical_delegation(asset_id, &voter, proposal.birth_block),
+			_ => Zero::zero(),
+		};
+
+		with_transaction(|| {
+			let plugins =
+				explain_on_vote::<T>(dao.owner.clone(), voter.clone(), voter.clone(), total);
+			let voting_power = on_vote::<T>(dao.owner.clone(), voter.clone(), voter.clone(), total);
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(VotingPowerExplanation {
+				balance: total.saturating_sub(delegated),
+				delegated,
+				plugins,
+				voting_power,
+			}))
+		})
+		.ok()
+	}
+
+	/// Check that a proposal can be finalized and evaluate whether it is accepted, without
+	/// changing any state.
+	///
+	/// Returns the proposal and whether it is accepted, or the error `finalize_proposal` would
+	/// fail with.
+	pub fn proposal_outcome(
+		proposal_id: T::ProposalId,
+	) -> Result<(ProposalOf<T>, bool), DispatchError> {
+		// check that a proposal exists with the given id
+		let proposal = <Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+
+		// check that the proposal is currently running
+		ensure!(proposal.status == ProposalStatus::Running, Error::<T>::ProposalStatusNotRunning);
+		let governance =
+			<Governances<T>>::get(&proposal.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
+		let current_block = <frame_system::Pallet<T>>::block_number();
+		let reveal_duration = <ProposalRevealDurations<T>>::get(proposal_id).unwrap_or_default();
+
+		// check that the proposal has run for its entire duration, including the reveal period
+		ensure!(
+			current_block - proposal.birth_block >
+				governance.proposal_duration.saturating_add(reveal_duration).into(),
+			Error::<T>::ProposalDurationHasNotPassed
+		);
+
+		let dao = Core::<T>::get_dao(&proposal.dao_id).expect("DAO exists");
+
+		let accepted = match governance.voting {
+			// we ship a majority vote implementation as default, that is requiring more people
+			// in favour than against and a threshold to be exceeded for a proposal to pass
+			Voting::Majority { minimum_majority_per_1024 } => {
+				let required_majority = Self::voting_power_share(
+					&dao,
+					proposal.birth_block,
+					minimum_majority_per_1024.into(),
+				);
+				// check for the required majority
+				proposal.in_favor > proposal.against &&
+					proposal.in_favor - proposal.against >= required_majority
+			},
+			// optimistic proposals pass unless the objections exceed a threshold
+			Voting::Optimistic { objection_threshold_per_1024 } => {
+				let objection_threshold = Self::voting_power_share(
+					&dao,
+					proposal.birth_block,
+					objection_threshold_per_1024,
+				);
+				proposal.against <= objection_threshold
+			},
+			// the custom voting mechanism allows for the interception with a hookpoint for
+			// custom logic, per default you just need more people in your favour than against
+			Voting::Custom => proposal.in_favor > proposal.against,
+		};
+		Ok((proposal, accepted))
+	}
+
+	/// A DAO together with its governance, if one has been set.
+	///
+	/// - `dao_id`: the DAO
+	pub fn dao_with_governance(dao_id: Vec<u8>) -> Option<(DaoOf<T>, Option<GovernanceOf<T>>)> {
+		let dao_id: DaoIdOf<T> = dao_id.try_into().ok()?;
+		let dao = Core::<T>::get_dao(&dao_id)?;
+		Some((dao, <Governances<T>>::get(&dao_id)))
+	}
+
+	/// The token balance of `who` in a DAO at `block`, `None` if the DAO has no token.
+	///
+	/// - `dao_id`: the DAO
+	/// - `who`: the token holder
+	/// - `block`: the block of the balance
+	pub fn historical_balance(
+		dao_id: Vec<u8>,
+		who: AccountIdOf<T>,
+		block: BlockNumberFor<T>,
+	) -> Option<BalanceOf<T>> {
+		let asset_id = Core::<T>::load_dao(dao_id).ok()?.asset_id?;
+		Some(T::ExposeAsset::total_historical_balance(asset_id, who, block))
+	}
+
+	/// The token supply of a DAO at `block`, `None` if the DAO has no token or the block is
+	/// beyond the history horizon.
+	///
+	/// - `dao_id`: the DAO
+	/// - `block`: the block of the supply
+	pub fn historical_supply(dao_id: Vec<u8>, block: BlockNumberFor<T>) -> Option<BalanceOf<T>> {
+		let asset_id = Core::<T>::load_dao(dao_id).ok()?.asset_id?;
+		T::ExposeAsset::total_historical_supply(asset_id, block)
+	}
+
+	/// The DAO of a proposal or proposal slot.
+	///
+	/// - `proposal_id`: the proposal
+	pub fn proposal_dao(proposal_id: T::ProposalId) -> Option<DaoIdOf<T>> {
+		<Proposals<T>>::get(proposal_id)
+			.map(|proposal| proposal.dao_id)
+			.or_else(|| <ProposalSlots<T>>::get(proposal_id).map(|slot| slot.dao_id))
+	}
+
+	/// The running proposals of a DAO with their ids and current tallies.
+	///
+	/// - `dao_id`: the DAO
+	pub fn active_proposals(dao_id: Vec<u8>) -> Vec<(T::ProposalId, ProposalOf<T>)> {
+		Self::get_active_proposals(dao_id, <frame_system::Pallet<T>>::block_number())
+	}
+
 	fn get_active_proposals(
 		dao_id: Vec<u8>,
 		current_block: BlockNumberFor<T>,
-	) -> Vec<ProposalOf<T>> {
-		let dao = match pallet_dao_core::Pallet::<T>::load_dao(dao_id.clone()) {
+	) -> Vec<(T::ProposalId, ProposalOf<T>)> {
+		let dao = match pallet_dao_core::Pallet::<T>::load_dao(dao_id) {
 			Ok(dao) => dao,
-			Err(_) => return Vec::<ProposalOf<T>>::new(),
+			Err(_) => return Vec::new(),
 		};
 
-		let governance = match <Governances<T>>::get(dao.id) {
+		let governance = match <Governances<T>>::get(&dao.id) {
 			Some(governance) => governance,
-			None => return Vec::<ProposalOf<T>>::new(),
+			None => return Vec::new(),
 		};
 
-		<Proposals<T>>::iter()
-			.filter(|(_, proposal)| {
-				proposal.dao_id == dao_id &&
-					proposal.status == ProposalStatus::Running &&
-					proposal.birth_block + governance.proposal_duration.into() >= current_block
+		<DaoProposals<T>>::iter_key_prefix(&dao.id)
+			.filter_map(|proposal_id| Some((proposal_id, <Proposals<T>>::get(proposal_id)?)))
+			.filter(|(proposal_id, proposal)| {
+				// proposals in commit-reveal mode stay active until their votes have been revealed
+				let duration = governance.proposal_duration.saturating_add(
+					<ProposalRevealDurations<T>>::get(proposal_id).unwrap_or_default(),
+				);
+				proposal.status == ProposalStatus::Running &&
+					proposal.birth_block + duration.into() >= current_block
 			})
-			.map(|(_, proposal)| proposal)
 			.collect::<Vec<_>>()
 	}
 }
@@ -42,6 +607,80 @@ impl<T: Config> ActiveProposals<BlockNumberFor<T>> for Pallet<T> {
 		current_block: BlockNumberFor<T>,
 	) -> Vec<BlockNumberFor<T>> {
 		let active_proposals = Self::get_active_proposals(dao_id, current_block);
-		active_proposals.iter().map(|proposal| proposal.birth_block).collect::<Vec<_>>()
+		active_proposals
+			.iter()
+			.map(|(_, proposal)| proposal.birth_block)
+			.collect::<Vec<_>>()
+	}
+}
+
+impl<T: Config> DaoCleanup<DaoIdOf<T>> for Pallet<T> {
+	fn cleanup_dao(dao_id: &DaoIdOf<T>, max_items: u32) -> bool {
+		// the proposals of the DAO may not all be indexed yet, see `migrations::v1`
+		if <ProposalIndexCursor<T>>::exists() {
+			return false
+		}
+		let mut remaining = max_items;
+		// reserved tokens are refunded unless the token has been destroyed already
+		let asset_id = Core::<T>::get_dao(dao_id).and_then(|dao| dao.asset_id);
+		let refund_tokens = |creator: &AccountIdOf<T>, amount: BalanceOf<T>| {
+			if let Some(asset_id) = asset_id {
+				let _ = T::ExposeAsset::unreserve(asset_id, creator, amount);
+			}
+		};
+
+		let proposal_ids = <DaoProposals<T>>::iter_key_prefix(dao_id)
+			.take(remaining as usize)
+			.collect::<Vec<_>>();
+		for proposal_id in proposal_ids {
+			if remaining.is_zero() {
+				return false
+			}
+			if let Some(slot) = <ProposalSlots<T>>::take(proposal_id) {
+				// an unused proposal slot, refunding its deposits
+				<SlotExpiries<T>>::remove(Self::slot_expiry(&slot), proposal_id);
+				CurrencyOf::<T>::unreserve(&slot.creator, <T as Config>::ProposalDeposit::get());
+				refund_tokens(&slot.creator, slot.token_deposit);
+			} else if let Some(proposal) = <Proposals<T>>::get(proposal_id) {
+				// a proposal together with its votes, a running one gets its deposits refunded
+				let votes = <Votes<T>>::clear_prefix(proposal_id, remaining, None);
+				remaining.saturating_reduce(votes.unique);
+				if votes.maybe_cursor.is_some() || remaining.is_zero() {
+					return false
+				}
+				// unrevealed commitments get their deposit refunded
+				for (voter, _) in
+					<VoteCommitments<T>>::drain_prefix(proposal_id).take(remaining as usize)
+				{
+					CurrencyOf::<T>::unreserve(&voter, T::VoteCommitmentDeposit::get());
+					remaining.saturating_dec();
+				}
+				if remaining.is_zero() {
+					return false
+				}
+
+				let token_deposit = <ProposalTokenDeposits<T>>::take(proposal_id);
+				if proposal.status == ProposalStatus::Running {
+					CurrencyOf::<T>::unreserve(
+						&proposal.creator,
+						<T as Config>::ProposalDeposit::get(),
+					);
+					refund_tokens(&proposal.creator, token_deposit.unwrap_or_default());
+				}
+				<Proposals<T>>::remove(proposal_id);
+				<ProposalRevealDurations<T>>::remove(proposal_id);
+			}
+			<ProposalKinds<T>>::remove(proposal_id);
+			<DaoProposals<T>>::remove(dao_id, proposal_id);
+			remaining.saturating_dec();
+		}
+
+		// with items to spare, no slot or proposal of the DAO is left
+		if remaining.is_zero() {
+			return false
+		}
+		<Governances<T>>::remove(dao_id);
+		<RevealDurations<T>>::remove(dao_id);
+		true
 	}
 }
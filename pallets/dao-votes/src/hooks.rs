@@ -1,6 +1,7 @@
 use crate::Config;
 use pallet_dao_core::BalanceOf;
 use pallet_hookpoints::Pallet as HP;
+use sp_std::prelude::*;
 
 pub fn on_vote<T: Config>(
 	owner: T::AccountId,
@@ -14,3 +15,46 @@ pub fn on_vote<T: Config>(
 
 	HP::<T>::execute::<BalanceOf<T>>(hp).unwrap_or(voting_power)
 }
+
+pub fn explain_on_vote<T: Config>(
+	owner: T::AccountId,
+	signer: T::AccountId,
+	voter: T::AccountId,
+	voting_power: BalanceOf<T>,
+) -> Vec<(T::AccountId, BalanceOf<T>)> {
+	let hp = HP::<T>::create("GenesisDao::explain_on_vote", owner, signer)
+		.add_arg::<T::AccountId>(voter)
+		.add_arg::<BalanceOf<T>>(voting_power);
+
+	HP::<T>::execute::<Vec<(T::AccountId, BalanceOf<T>)>>(hp).unwrap_or_default()
+}
+
+pub fn on_proposal_create<T: Config>(
+	owner: T::AccountId,
+	signer: T::AccountId,
+	creator: T::AccountId,
+	payload: Vec<u8>,
+) -> bool {
+	let hp = HP::<T>::create("GenesisDao::on_proposal_create", owner, signer)
+		.add_arg::<T::AccountId>(creator)
+		.add_arg::<Vec<u8>>(payload);
+
+	HP::<T>::execute::<bool>(hp).unwrap_or(true)
+}
+
+pub fn on_proposal_metadata<T: Config>(
+	owner: T::AccountId,
+	signer: T::AccountId,
+	creator: T::AccountId,
+	payload: Vec<u8>,
+	meta: Vec<u8>,
+	hash: Vec<u8>,
+) -> bool {
+	let hp = HP::<T>::create("GenesisDao::on_proposal_metadata", owner, signer)
+		.add_arg::<T::AccountId>(creator)
+		.add_arg::<Vec<u8>>(payload)
+		.add_arg::<Vec<u8>>(meta)
+		.add_arg::<Vec<u8>>(hash);
+
+	HP::<T>::execute::<bool>(hp).unwrap_or(true)
+}
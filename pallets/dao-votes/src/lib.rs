@@ -2,7 +2,7 @@
 use sp_std::prelude::*;
 
 use frame_support::{
-	sp_runtime::traits::{One, Saturating, Zero},
+	sp_runtime::traits::{IdentifyAccount, One, Saturating, Verify},
 	storage::bounded_vec::BoundedVec,
 	traits::ReservableCurrency,
 };
@@ -30,19 +30,20 @@ use pallet_dao_core::{
 
 mod functions;
 mod hooks;
+pub mod migrations;
 pub mod weights;
 
 use weights::WeightInfo;
 
+/// The tag signed votes are prefixed with, so that their signatures cannot be reused elsewhere.
+pub const SIGNED_VOTE_TAG: &[u8] = b"genesis-dao/vote";
+
 #[frame_support::pallet]
 pub mod pallet {
 
 	use super::*;
-	use crate::hooks::on_vote;
-	use commons::traits::pallets::AssetInterface;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use types::BalanceOf as AssetBalanceOf;
 
 	#[pallet::storage]
 	pub(super) type Governances<T: Config> =
@@ -52,23 +53,96 @@ pub mod pallet {
 	pub(super) type ProposalSlots<T: Config> =
 		StorageMap<_, Twox64Concat, T::ProposalId, ProposalSlotOf<T>>;
 
+	/// The proposal slots by the block in which they expire.
+	#[pallet::storage]
+	pub(super) type SlotExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, T::ProposalId, ()>;
+
+	/// The proposal slots and proposals by the DAO they belong to.
+	#[pallet::storage]
+	pub(super) type DaoProposals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, DaoIdOf<T>, Twox64Concat, T::ProposalId, ()>;
+
+	/// The first block of `SlotExpiries` that has not been fully processed yet.
+	#[pallet::storage]
+	pub(super) type NextSlotExpiry<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// The last proposal indexed in `DaoProposals` by the migration to storage version 1, the
+	/// proposals after it are indexed in `on_idle`, see `migrations::v1::index_proposals`.
+	#[pallet::storage]
+	pub(super) type ProposalIndexCursor<T: Config> = StorageValue<_, T::ProposalId>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn proposals)]
 	pub(super) type Proposals<T: Config> =
 		StorageMap<_, Twox64Concat, T::ProposalId, ProposalOf<T>>;
 
+	/// The typed payloads of proposals.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_kind)]
+	pub(super) type ProposalKinds<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, ProposalKindOf<T>>;
+
 	#[pallet::storage]
 	pub(super) type Votes<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, T::ProposalId, Twox64Concat, AccountIdOf<T>, bool>;
 
+	/// The number of blocks after the voting period in which committed votes can be revealed.
+	///
+	/// DAOs with an entry here vote in commit-reveal mode.
+	#[pallet::storage]
+	#[pallet::getter(fn reveal_duration)]
+	pub type RevealDurations<T: Config> = StorageMap<_, Twox64Concat, DaoIdOf<T>, u32>;
+
+	/// The DAO tokens reserved from the creators of proposals.
+	#[pallet::storage]
+	pub(super) type ProposalTokenDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, BalanceOf<T>>;
+
+	/// The reveal duration of proposals that were created in commit-reveal mode.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_reveal_duration)]
+	pub(super) type ProposalRevealDurations<T: Config> =
+		StorageMap<_, Twox64Concat, T::ProposalId, u32>;
+
+	/// The hashed votes committed by voters, waiting to be revealed.
+	#[pallet::storage]
+	pub(super) type VoteCommitments<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::ProposalId,
+		Twox64Concat,
+		AccountIdOf<T>,
+		<T as frame_system::Config>::Hash,
+	>;
+
+	/// The next nonce expected for an off-chain signed vote of an account.
+	#[pallet::storage]
+	#[pallet::getter(fn vote_nonce)]
+	pub type VoteNonces<T: Config> = StorageMap<_, Twox64Concat, AccountIdOf<T>, u32, ValueQuery>;
+
 	/// Internal incrementor of all proposals created by this module.
 	#[pallet::storage]
 	#[pallet::getter(fn get_current_proposal_id)]
 	pub type CurrentProposalId<T: Config> = StorageValue<_, T::ProposalId, ValueQuery>;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let consumed = Self::expire_proposal_slots(now, remaining_weight);
+			consumed.saturating_add(migrations::v1::index_proposals::<T>(
+				remaining_weight.saturating_sub(consumed),
+			))
+		}
+	}
+
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config + pallet_dao_core::Config + pallet_hookpoints::Config
@@ -90,6 +164,29 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxProposals: Get<u32>;
 
+		/// The number of blocks after which a proposal slot without metadata expires and its
+		/// deposits are refunded.
+		#[pallet::constant]
+		type ProposalSlotExpiry: Get<u32>;
+
+		/// The maximum length of the encoded arguments of a contract call proposal.
+		#[pallet::constant]
+		type MaxCallDataLength: Get<u32>;
+
+		/// The signature type voters use to sign votes that are submitted on their behalf.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// The public key type of off-chain voters, resolving to their account.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The maximum number of signed votes that can be submitted in one batch.
+		#[pallet::constant]
+		type MaxSignedVotes: Get<u32>;
+
+		/// The deposit reserved for a committed vote until it is revealed or removed.
+		#[pallet::constant]
+		type VoteCommitmentDeposit: Get<DepositBalanceOf<Self>>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -104,6 +201,9 @@ pub mod pallet {
 		ProposalMetadataSet {
 			proposal_id: T::ProposalId,
 		},
+		ProposalSlotExpired {
+			proposal_id: T::ProposalId,
+		},
 		ProposalFaulted {
 			proposal_id: T::ProposalId,
 			reason: Vec<u8>,
@@ -125,12 +225,38 @@ pub mod pallet {
 			voter: AccountIdOf<T>,
 			in_favor: Option<bool>,
 		},
+		VoteCommitted {
+			proposal_id: T::ProposalId,
+			voter: AccountIdOf<T>,
+		},
+		SetCommitReveal {
+			dao_id: DaoIdOf<T>,
+			reveal_duration: Option<u32>,
+		},
 		SetGovernanceMajorityVote {
 			dao_id: DaoIdOf<T>,
 			proposal_duration: u32,
 			proposal_token_deposit: BalanceOf<T>,
 			minimum_majority_per_1024: u8,
 		},
+		SetGovernanceOptimisticVote {
+			dao_id: DaoIdOf<T>,
+			proposal_duration: u32,
+			proposal_token_deposit: BalanceOf<T>,
+			objection_threshold_per_1024: u16,
+		},
+		SubDaoGovernanceChanged {
+			dao_id: DaoIdOf<T>,
+			proposal_id: T::ProposalId,
+		},
+		VoteCommitmentRemoved {
+			proposal_id: T::ProposalId,
+			voter: AccountIdOf<T>,
+		},
+		MembershipChangeFailed {
+			proposal_id: T::ProposalId,
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::error]
@@ -144,6 +270,21 @@ pub mod pallet {
 		ProposalDurationHasPassed,
 		SenderIsNotDaoOwner,
 		SenderIsNotProposalCreator,
+		InvalidSignature,
+		InvalidNonce,
+		CommitRevealNotEnabled,
+		VoteMustBeCommitted,
+		VoteCommitmentDoesNotExist,
+		VoteCommitmentMismatch,
+		RevealPeriodNotStarted,
+		RevealPeriodHasPassed,
+		ProposalKindRejected,
+		NotASubDao,
+		NotAMember,
+		RevealPeriodNotOver,
+		ProposalMetadataRejected,
+		RevealDurationZero,
+		ObjectionThresholdTooHigh,
 	}
 
 	#[pallet::call]
@@ -151,82 +292,29 @@ pub mod pallet {
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_proposal())]
 		pub fn create_proposal(origin: OriginFor<T>, dao_id: Vec<u8>) -> DispatchResult {
-			let sender = ensure_signed(origin.clone())?;
-			let dao = pallet_dao_core::Pallet::<T>::load_dao(dao_id)?;
-			let dao_id = dao.id;
-			let asset_id = dao.asset_id.ok_or(Error::<T>::DaoTokenNotYetIssued)?;
-			let governance =
-				<Governances<T>>::get(dao_id.clone()).ok_or(Error::<T>::GovernanceNotSet)?;
-
-			let deposit = <T as Config>::ProposalDeposit::get();
-
-			// reserve currency
-			CurrencyOf::<T>::reserve(&sender, deposit)?;
-
-			// reserve DAO token, but unreserve currency if that fails
-			if let Err(error) =
-				T::ExposeAsset::reserve(asset_id, &sender, governance.proposal_token_deposit)
-			{
-				CurrencyOf::<T>::unreserve(&sender, deposit);
-				Err(error)?;
-			};
-			// increase proposal counter
-			<CurrentProposalId<T>>::mutate(|id| id.saturating_inc());
-
-			// store a proposal slot
-			<ProposalSlots<T>>::insert(
-				Self::get_current_proposal_id(),
-				ProposalSlot { dao_id: dao_id.clone(), creator: sender.clone() },
-			);
-			// emit an event
-			Self::deposit_event(Event::<T>::ProposalCreated {
-				dao_id,
-				creator: sender,
-				proposal_id: Self::get_current_proposal_id(),
-			});
-
+			let sender = ensure_signed(origin)?;
+			Self::do_create_proposal(sender, dao_id, ProposalKind::Text)?;
 			Ok(())
 		}
 
+		/// Set the metadata of a proposal slot, which starts the proposal.
+		///
+		/// The payload and metadata are passed to the `on_proposal_metadata` hook point of the
+		/// DAO, which can reject them.
+		///
+		/// - `proposal_id`: The proposal slot to start.
+		/// - `meta`: The metadata URL of the proposal.
+		/// - `hash`: The hash of the metadata.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_proposal())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_metadata())]
 		pub fn set_metadata(
 			origin: OriginFor<T>,
 			proposal_id: T::ProposalId,
 			meta: Vec<u8>,
 			hash: Vec<u8>,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin.clone())?;
-
-			let slot =
-				ProposalSlots::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
-			ensure!(sender == slot.creator, Error::<T>::SenderIsNotProposalCreator);
-
-			let meta: BoundedVec<_, _> =
-				meta.try_into().map_err(|_| DaoError::<T>::MetadataInvalidLengthTooLong)?;
-			let hash: BoundedVec<_, _> =
-				hash.try_into().map_err(|_| DaoError::<T>::HashInvalidWrongLength)?;
-
-			let birth_block = <frame_system::Pallet<T>>::block_number();
-			// store the proposal
-			ProposalSlots::<T>::remove(proposal_id);
-			Proposals::<T>::insert(
-				proposal_id,
-				Proposal {
-					dao_id: slot.dao_id,
-					creator: sender,
-					birth_block,
-					status: ProposalStatus::Running,
-					in_favor: Zero::zero(),
-					against: Zero::zero(),
-					meta,
-					meta_hash: hash,
-				},
-			);
-
-			// emit an event
-			Self::deposit_event(Event::<T>::ProposalMetadataSet { proposal_id });
-			Ok(())
+			let sender = ensure_signed(origin)?;
+			Self::do_set_metadata(sender, proposal_id, meta, hash)
 		}
 
 		#[pallet::call_index(2)]
@@ -266,54 +354,13 @@ pub mod pallet {
 			proposal_id: T::ProposalId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
+			let (mut proposal, accepted) = Self::proposal_outcome(proposal_id)?;
+			proposal.status =
+				if accepted { ProposalStatus::Accepted } else { ProposalStatus::Rejected };
 
-			// check that a proposal exists with the given id
-			let mut proposal =
-				<Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
-
-			// check that the proposal is currently running
-			ensure!(
-				proposal.status == ProposalStatus::Running,
-				Error::<T>::ProposalStatusNotRunning
-			);
-			let governance =
-				<Governances<T>>::get(&proposal.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
-			let current_block = <frame_system::Pallet<T>>::block_number();
-
-			// check that the proposal has run for its entire duration
-			ensure!(
-				current_block - proposal.birth_block > governance.proposal_duration.into(),
-				Error::<T>::ProposalDurationHasNotPassed
-			);
-
-			let asset_id = Core::<T>::get_dao(&proposal.dao_id)
-				.expect("DAO exists")
-				.asset_id
-				.expect("asset has been issued");
-
-			// per default you just need to have more people in your favour than against ...
-			if proposal.in_favor > proposal.against && {
-				match governance.voting {
-					// we ship a majority vote implementation as default, that is requiring a
-					// threshold to be exceeded for a proposal to pass
-					Voting::Majority { minimum_majority_per_1024 } => {
-						let token_supply =
-							T::ExposeAsset::total_historical_supply(asset_id, proposal.birth_block)
-								.expect("History exists (horizon checked above)");
-						let required_majority = token_supply /
-							Into::<AssetBalanceOf<T>>::into(1024_u32) *
-							minimum_majority_per_1024.into();
-						// check for the required majority
-						proposal.in_favor - proposal.against >= required_majority
-					},
-					// the custom voting mechanism allows for the interception with a hookpoint for
-					// custom logic.
-					Voting::Custom => true,
-				}
-			} {
-				proposal.status = ProposalStatus::Accepted;
-			} else {
-				proposal.status = ProposalStatus::Rejected;
+			// apply the payload of accepted proposals that change the state of DAOs
+			if accepted {
+				Self::apply_proposal_kind(proposal_id, &proposal.dao_id);
 			}
 
 			// unreserve proposal deposit
@@ -340,66 +387,7 @@ pub mod pallet {
 			in_favor: Option<bool>,
 		) -> DispatchResult {
 			let voter: <T as frame_system::Config>::AccountId = ensure_signed(origin)?;
-
-			// check that a proposal exists with the given id
-			let mut proposal =
-				<Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
-
-			// check that the proposal is running
-			ensure!(
-				proposal.status == ProposalStatus::Running,
-				Error::<T>::ProposalStatusNotRunning
-			);
-
-			let governance =
-				<Governances<T>>::get(&proposal.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
-
-			// check that the proposal has not yet run for its entire duration
-			ensure!(
-				<frame_system::Pallet<T>>::block_number() - proposal.birth_block <=
-					governance.proposal_duration.into(),
-				Error::<T>::ProposalDurationHasPassed
-			);
-
-			let vote = <Votes<T>>::get(proposal_id, &voter);
-			if vote == in_favor {
-				// vote already stored
-				return Ok(())
-			}
-
-			<Votes<T>>::set(proposal_id, &voter, in_favor);
-			let dao = Core::<T>::get_dao(&proposal.dao_id).expect("DAO exists");
-			let asset_id = dao.asset_id.expect("asset has been issued");
-			let voting_power =
-				T::ExposeAsset::total_historical_balance(asset_id, &voter, proposal.birth_block);
-
-			let voting_power =
-				on_vote::<T>(dao.owner.clone(), voter.clone(), voter.clone(), voting_power);
-			// undo old vote
-			match vote {
-				Some(true) => {
-					proposal.in_favor -= voting_power;
-				},
-				Some(false) => {
-					proposal.against -= voting_power;
-				},
-				None => {},
-			}
-			// count new vote
-			match in_favor {
-				Some(true) => {
-					proposal.in_favor += voting_power;
-				},
-				Some(false) => {
-					proposal.against += voting_power;
-				},
-				None => {},
-			}
-			// record updated proposal counts
-			<Proposals<T>>::insert(proposal_id, proposal);
-
-			Self::deposit_event(Event::<T>::VoteCast { proposal_id, voter, in_favor });
-			Ok(())
+			Self::do_vote(voter, proposal_id, in_favor)
 		}
 
 		#[pallet::call_index(6)]
@@ -453,5 +441,262 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::ProposalImplemented { proposal_id });
 			Ok(())
 		}
+
+		/// Submit a batch of votes that have been signed off-chain by their voters.
+		///
+		/// This allows a relayer to pay the fees for voters who do not hold the native currency.
+		/// Every voter signs the SCALE encoded `(SIGNED_VOTE_TAG, genesis_hash, proposal_id,
+		/// in_favor, nonce)` with the nonce being their current `VoteNonces` entry. The batch is
+		/// rejected as a whole if any signature or nonce is invalid.
+		///
+		/// - `votes`: The signed votes to record.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_signed_votes(votes.len() as u32))]
+		pub fn submit_signed_votes(
+			origin: OriginFor<T>,
+			votes: BoundedVec<SignedVoteOf<T>, T::MaxSignedVotes>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			for signed_vote in votes {
+				let SignedVote { proposal_id, voter, in_favor, nonce, signature } = signed_vote;
+
+				ensure!(nonce == Self::vote_nonce(&voter), Error::<T>::InvalidNonce);
+				let payload = Self::signed_vote_payload(proposal_id, in_favor, nonce);
+				ensure!(signature.verify(&payload[..], &voter), Error::<T>::InvalidSignature);
+
+				<VoteNonces<T>>::insert(&voter, nonce.saturating_add(1));
+				Self::do_vote(voter, proposal_id, in_favor)?;
+			}
+
+			Ok(())
+		}
+
+		/// Enable or disable commit-reveal voting for a DAO.
+		///
+		/// In commit-reveal mode votes are committed as a hash during the voting period and
+		/// revealed in a subsequent reveal period. Only revealed votes are counted. The mode
+		/// applies to proposals that receive their metadata afterwards, running proposals keep
+		/// the mode they were created with.
+		///
+		/// - `dao_id`: The DAO to configure.
+		/// - `reveal_duration`: The number of blocks of the reveal period, `None` to disable. A
+		///   reveal period of zero blocks is rejected, no vote could be revealed in it.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_commit_reveal())]
+		pub fn set_commit_reveal(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			reveal_duration: Option<u32>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = pallet_dao_core::Pallet::<T>::load_dao(dao_id)?;
+			let dao_id = dao.id;
+			ensure!(dao.owner == sender, DaoError::<T>::DaoSignerNotOwner);
+			ensure!(reveal_duration != Some(0), Error::<T>::RevealDurationZero);
+			<RevealDurations<T>>::set(dao_id.clone(), reveal_duration);
+			Self::deposit_event(Event::<T>::SetCommitReveal { dao_id, reveal_duration });
+			Ok(())
+		}
+
+		/// Commit a hidden vote on a proposal in commit-reveal mode.
+		///
+		/// The `VoteCommitmentDeposit` is reserved until the vote is revealed or the commitment
+		/// is removed.
+		///
+		/// - `proposal_id`: The proposal to vote on.
+		/// - `commitment`: The hash of the SCALE encoded `(proposal_id, voter, in_favor, salt)`,
+		///   see `Pallet::vote_commitment`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::commit_vote())]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			commitment: <T as frame_system::Config>::Hash,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let proposal =
+				<Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(
+				proposal.status == ProposalStatus::Running,
+				Error::<T>::ProposalStatusNotRunning
+			);
+			ensure!(
+				<ProposalRevealDurations<T>>::contains_key(proposal_id),
+				Error::<T>::CommitRevealNotEnabled
+			);
+
+			let governance =
+				<Governances<T>>::get(&proposal.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() - proposal.birth_block <=
+					governance.proposal_duration.into(),
+				Error::<T>::ProposalDurationHasPassed
+			);
+
+			if !<VoteCommitments<T>>::contains_key(proposal_id, &voter) {
+				CurrencyOf::<T>::reserve(&voter, T::VoteCommitmentDeposit::get())?;
+			}
+			<VoteCommitments<T>>::insert(proposal_id, &voter, commitment);
+			Self::deposit_event(Event::<T>::VoteCommitted { proposal_id, voter });
+			Ok(())
+		}
+
+		/// Reveal a committed vote during the reveal period, counting it towards the proposal.
+		///
+		/// - `proposal_id`: The proposal the vote was committed for.
+		/// - `in_favor`: The committed vote.
+		/// - `salt`: The salt used for the commitment.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reveal_vote())]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			in_favor: bool,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let proposal =
+				<Proposals<T>>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(
+				proposal.status == ProposalStatus::Running,
+				Error::<T>::ProposalStatusNotRunning
+			);
+			let reveal_duration = <ProposalRevealDurations<T>>::get(proposal_id)
+				.ok_or(Error::<T>::CommitRevealNotEnabled)?;
+			let governance =
+				<Governances<T>>::get(&proposal.dao_id).ok_or(Error::<T>::GovernanceNotSet)?;
+
+			// check that we are within the reveal period
+			let elapsed = <frame_system::Pallet<T>>::block_number() - proposal.birth_block;
+			ensure!(
+				elapsed > governance.proposal_duration.into(),
+				Error::<T>::RevealPeriodNotStarted
+			);
+			ensure!(
+				elapsed <= governance.proposal_duration.saturating_add(reveal_duration).into(),
+				Error::<T>::RevealPeriodHasPassed
+			);
+
+			let commitment = <VoteCommitments<T>>::get(proposal_id, &voter)
+				.ok_or(Error::<T>::VoteCommitmentDoesNotExist)?;
+			ensure!(
+				commitment == Self::vote_commitment(proposal_id, &voter, in_favor, salt),
+				Error::<T>::VoteCommitmentMismatch
+			);
+
+			<VoteCommitments<T>>::remove(proposal_id, &voter);
+			CurrencyOf::<T>::unreserve(&voter, T::VoteCommitmentDeposit::get());
+			Self::count_vote(voter, proposal_id, proposal, Some(in_favor))
+		}
+
+		/// Set an optimistic governance for a DAO.
+		///
+		/// Proposals are accepted at the end of their voting period unless the votes against
+		/// them exceed `objection_threshold_per_1024` of the historical token supply. The
+		/// threshold can be at most 1024.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_governance_optimistic_vote())]
+		pub fn set_governance_optimistic_vote(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			proposal_duration: u32,
+			proposal_token_deposit: BalanceOf<T>,
+			objection_threshold_per_1024: u16,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let dao = pallet_dao_core::Pallet::<T>::load_dao(dao_id)?;
+			let dao_id = dao.id;
+			ensure!(dao.owner == sender, DaoError::<T>::DaoSignerNotOwner);
+			let voting = Voting::Optimistic { objection_threshold_per_1024 };
+			Self::ensure_voting_is_valid(&voting)?;
+			let gov = GovernanceOf::<T> { proposal_duration, proposal_token_deposit, voting };
+			<Governances<T>>::set(dao_id.clone(), Some(gov));
+			Self::deposit_event(Event::<T>::SetGovernanceOptimisticVote {
+				dao_id,
+				proposal_duration,
+				proposal_token_deposit,
+				objection_threshold_per_1024,
+			});
+			Ok(())
+		}
+
+		/// Create a proposal with a typed payload.
+		///
+		/// The payload is passed to the `on_proposal_create` hook point of the DAO, which can
+		/// reject it.
+		///
+		/// - `dao_id`: The DAO to create the proposal for.
+		/// - `kind`: The typed payload of the proposal.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_proposal_of_kind())]
+		pub fn create_proposal_of_kind(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			kind: ProposalKindOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_create_proposal(sender, dao_id, kind)?;
+			Ok(())
+		}
+
+		/// Create a proposal with a typed payload and its metadata in a single step.
+		///
+		/// - `dao_id`: The DAO to create the proposal for.
+		/// - `kind`: The typed payload of the proposal.
+		/// - `meta`: The metadata URL of the proposal.
+		/// - `hash`: The hash of the metadata.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_proposal_with_metadata())]
+		pub fn create_proposal_with_metadata(
+			origin: OriginFor<T>,
+			dao_id: Vec<u8>,
+			kind: ProposalKindOf<T>,
+			meta: Vec<u8>,
+			hash: Vec<u8>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let proposal_id = Self::do_create_proposal(sender.clone(), dao_id, kind)?;
+			Self::do_set_metadata(sender, proposal_id, meta, hash)
+		}
+
+		/// Remove an unrevealed vote commitment once it can no longer be revealed, refunding its
+		/// deposit.
+		///
+		/// - `proposal_id`: The proposal the vote was committed for.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_vote_commitment())]
+		pub fn remove_vote_commitment(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+			ensure!(
+				<VoteCommitments<T>>::contains_key(proposal_id, &voter),
+				Error::<T>::VoteCommitmentDoesNotExist
+			);
+
+			// commitments on running proposals can be removed after the reveal period
+			if let Some(proposal) = <Proposals<T>>::get(proposal_id) {
+				if proposal.status == ProposalStatus::Running {
+					let governance = <Governances<T>>::get(&proposal.dao_id)
+						.ok_or(Error::<T>::GovernanceNotSet)?;
+					let reveal_duration =
+						<ProposalRevealDurations<T>>::get(proposal_id).unwrap_or_default();
+					ensure!(
+						<frame_system::Pallet<T>>::block_number() - proposal.birth_block >
+							governance.proposal_duration.saturating_add(reveal_duration).into(),
+						Error::<T>::RevealPeriodNotOver
+					);
+				}
+			}
+
+			<VoteCommitments<T>>::remove(proposal_id, &voter);
+			CurrencyOf::<T>::unreserve(&voter, T::VoteCommitmentDeposit::get());
+			Self::deposit_event(Event::<T>::VoteCommitmentRemoved { proposal_id, voter });
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,126 @@
+//! Storage migrations for the dao-votes pallet.
+use super::*;
+
+pub mod v1 {
+	use super::*;
+	use codec::{Decode, Encode, MaxEncodedLen};
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::traits::Zero,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+	use sp_std::marker::PhantomData;
+
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+
+	/// The proposal slot up to storage version 0, before `created_at` and `token_deposit`.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct OldProposalSlot<DaoId, AccountId> {
+		pub dao_id: DaoId,
+		pub creator: AccountId,
+	}
+
+	/// Migrate dao-votes from storage version 0 to 1.
+	///
+	/// - adds `created_at` and `token_deposit` to the proposal slots; the slots are treated as
+	///   created at the upgrade, the DAO tokens reserved for them are unknown and recorded as zero,
+	///   so they are not refunded by the pallet
+	/// - indexes the proposal slots by their expiry, starting the expiry at the upgrade
+	/// - indexes the proposal slots by their DAO
+	/// - starts indexing the proposals by their DAO, the remaining proposals are indexed in
+	///   `on_idle`, see `index_proposals`
+	///
+	/// The slots change their layout, so they are migrated at once. Only slots that did not
+	/// receive metadata yet are kept as slots, they are far fewer than the proposals.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut slots = 0u64;
+			<ProposalSlots<T>>::translate::<OldProposalSlot<DaoIdOf<T>, AccountIdOf<T>>, _>(
+				|proposal_id, old| {
+					slots += 1;
+					let slot = ProposalSlot {
+						dao_id: old.dao_id,
+						creator: old.creator,
+						created_at: now,
+						token_deposit: Zero::zero(),
+					};
+					<SlotExpiries<T>>::insert(Pallet::<T>::slot_expiry(&slot), proposal_id, ());
+					<DaoProposals<T>>::insert(&slot.dao_id, proposal_id, ());
+					Some(slot)
+				},
+			);
+			<NextSlotExpiry<T>>::put(now);
+			// the first proposal is indexed right away, `index_proposals` continues after it
+			if let Some((proposal_id, proposal)) = <Proposals<T>>::iter().next() {
+				<DaoProposals<T>>::insert(&proposal.dao_id, proposal_id, ());
+				<ProposalIndexCursor<T>>::put(proposal_id);
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(slots + 2, slots * 3 + 4)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let slots = <ProposalSlots<T>>::iter_keys().count() as u32;
+			Ok(slots.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let slots = <u32>::decode(&mut &state[..])
+				.map_err(|_| "cannot decode the pre-upgrade count")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "storage version not updated");
+			ensure!(
+				<ProposalSlots<T>>::iter_values().count() as u32 == slots,
+				"proposal slots lost in migration"
+			);
+			Ok(())
+		}
+	}
+
+	/// Index the proposals after `ProposalIndexCursor` by their DAO, as many as the
+	/// `remaining_weight` allows, until all proposals are indexed.
+	///
+	/// Returns the weight consumed, which never exceeds `remaining_weight`.
+	pub fn index_proposals<T: Config>(remaining_weight: Weight) -> Weight {
+		let read = T::DbWeight::get().reads(1);
+		let index = T::DbWeight::get().reads_writes(1, 1);
+
+		// reading and updating the cursor
+		let mut consumed = read.saturating_add(T::DbWeight::get().writes(1));
+		if !remaining_weight.all_gte(consumed) {
+			return Weight::zero()
+		}
+		let cursor = match <ProposalIndexCursor<T>>::get() {
+			Some(cursor) => cursor,
+			None => return read,
+		};
+		let mut proposals = <Proposals<T>>::iter_from(<Proposals<T>>::hashed_key_for(cursor));
+		let mut last = cursor;
+		while remaining_weight.all_gte(consumed.saturating_add(index)) {
+			consumed.saturating_accrue(index);
+			match proposals.next() {
+				Some((proposal_id, proposal)) => {
+					<DaoProposals<T>>::insert(&proposal.dao_id, proposal_id, ());
+					last = proposal_id;
+				},
+				None => {
+					<ProposalIndexCursor<T>>::kill();
+					return consumed
+				},
+			}
+		}
+		<ProposalIndexCursor<T>>::put(last);
+		consumed
+	}
+}
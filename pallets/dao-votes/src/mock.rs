@@ -2,13 +2,15 @@ use crate as pallet_dao_votes;
 use frame_support::{
 	parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8},
+	PalletId,
 };
 use frame_system as system;
 use sp_core::H256;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	AccountId32, BuildStorage,
+	AccountId32, BuildStorage, MultiSignature, MultiSigner,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -151,10 +153,12 @@ impl pallet_contracts::Config for Test {
 parameter_types! {
 	pub const ApprovalDeposit: Balance = 1;
 	pub const AssetsStringLimit: u32 = 50;
+	pub const DaoCorePalletId: PalletId = PalletId(*b"gdao/dao");
 }
 
 impl pallet_dao_assets::Config for Test {
 	type ActiveProposals = DaoVotes;
+	type AssetDaos = DaoCore;
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
 	type AssetIdParameter = u32;
@@ -178,10 +182,25 @@ impl pallet_dao_core::Config for Test {
 	type MaxLengthMetadata = ConstU32<256>;
 	type Currency = Balances;
 	type DaoDeposit = ConstU128<10>;
+	type DaoDepositPerByte = ConstU128<0>;
 	type TokenUnits = ConstU8<10>;
+	type MaxTokenUnits = ConstU8<18>;
+	type MaxMetadataHistory = ConstU32<3>;
+	type MaxAttachedDaos = ConstU32<3>;
+	type MaxMembers = ConstU32<10>;
+	type RequireDaoIdCommitment = ConstBool<false>;
+	type DaoIdCommitmentDelay = ConstU32<2>;
+	type DaoIdCommitmentExpiry = ConstU32<100>;
 	type AssetId = u32;
 	type ExposeAsset = Assets;
 	type CoreWeightInfo = ();
+	type MetadataValidator = pallet_dao_core::hooks::OnSetMetadata<Test>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type OwnershipTransferExpiry = ConstU32<100>;
+	type DaoCleanup = DaoVotes;
+	type DissolveItemsLimit = ConstU32<5>;
+	type PalletId = DaoCorePalletId;
+	type MaxAllocations = ConstU32<10>;
 }
 
 impl pallet_hookpoints::Config for Test {
@@ -195,6 +214,12 @@ impl pallet_dao_votes::Config for Test {
 	type ProposalDeposit = ConstU128<10>;
 	type ProposalId = u32;
 	type MaxProposals = ConstU32<25>;
+	type ProposalSlotExpiry = ConstU32<100>;
+	type MaxCallDataLength = ConstU32<1024>;
+	type OffchainSignature = MultiSignature;
+	type OffchainPublic = MultiSigner;
+	type MaxSignedVotes = ConstU32<50>;
+	type VoteCommitmentDeposit = ConstU128<1>;
 	type WeightInfo = ();
 }
 
@@ -211,6 +236,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	.unwrap();
 
 	let mut ext = sp_io::TestExternalities::new(t);
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
@@ -67,6 +67,21 @@ pub fn setup_dao_with_governance_and_no_duration<T: Config>(caller: T::AccountId
 	dao_id
 }
 
+/// Enables commit-reveal voting for the given dao_id
+/// - `caller`: AccountId of the dao creator
+/// - `dao_id`: id of the dao
+/// - `reveal_duration`: number of blocks of the reveal period
+pub fn setup_commit_reveal<T: Config>(caller: T::AccountId, dao_id: Vec<u8>, reveal_duration: u32) {
+	assert_eq!(
+		Votes::<T>::set_commit_reveal(
+			RawOrigin::Signed(caller).into(),
+			dao_id,
+			Some(reveal_duration)
+		),
+		Ok(())
+	);
+}
+
 /// Creates a proposal id for the given dao_id and caller
 /// - `caller`: AccountId of the dao creator
 /// - `dao_id`: id of the dao
@@ -1,6 +1,23 @@
-use crate::{mock::*, test_utils::*, types::*, Config, Error, ProposalSlots, Proposals, Votes};
-use frame_support::{assert_noop, assert_ok, traits::TypedGet};
-use pallet_dao_core::{CurrencyOf, Error as DaoError};
+use crate::{
+	migrations::v1::{MigrateToV1, OldProposalSlot},
+	mock::*,
+	test_utils::*,
+	types::*,
+	Config, DaoProposals, Error, Event, Governances, NextSlotExpiry, ProposalIndexCursor,
+	ProposalKinds, ProposalSlots, Proposals, SlotExpiries, VoteCommitments, VoteNonces, Votes,
+};
+use codec::Encode;
+use commons::traits::pallets::DaoCleanup;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion, TypedGet},
+	weights::Weight,
+	BoundedVec,
+};
+use pallet_dao_core::{CurrencyOf, DaoIdOf, Error as DaoError};
+use sp_core::{sr25519, Pair};
+use sp_runtime::MultiSignature;
 
 #[test]
 fn can_create_a_proposal() {
@@ -79,6 +96,37 @@ fn can_create_a_proposal() {
 	});
 }
 
+#[test]
+fn can_create_a_proposal_of_kind() {
+	new_test_ext().execute_with(|| {
+		let sender = ALICE;
+		let origin = RuntimeOrigin::signed(sender.clone());
+		let dao_id = setup_dao_with_governance::<Test>(sender.clone());
+
+		// plain proposals are text proposals
+		let prop_id = create_proposal_id::<Test>(sender.clone(), dao_id.clone());
+		assert_eq!(<ProposalKinds<Test>>::get(prop_id), Some(ProposalKind::Text));
+
+		let kind = ProposalKind::ContractCall {
+			contract: BOB,
+			selector: [1, 2, 3, 4],
+			data: vec![42].try_into().unwrap(),
+		};
+		assert_ok!(DaoVotes::create_proposal_of_kind(origin.clone(), dao_id.clone(), kind.clone()));
+		let prop_id = DaoVotes::get_current_proposal_id();
+		assert_eq!(<ProposalKinds<Test>>::get(prop_id), Some(kind));
+		assert_eq!(<ProposalSlots<Test>>::get(prop_id).unwrap().creator, sender);
+
+		let kind = ProposalKind::GovernanceChange {
+			proposal_duration: 10,
+			proposal_token_deposit: 1,
+			voting: Voting::Optimistic { objection_threshold_per_1024: 100 },
+		};
+		assert_ok!(DaoVotes::create_proposal_of_kind(origin, dao_id, kind.clone()));
+		assert_eq!(DaoVotes::proposal_kind(DaoVotes::get_current_proposal_id()), Some(kind));
+	});
+}
+
 #[test]
 fn can_set_metadata() {
 	new_test_ext().execute_with(|| {
@@ -100,6 +148,93 @@ fn can_set_metadata() {
 	});
 }
 
+#[test]
+fn can_create_a_proposal_with_metadata() {
+	new_test_ext().execute_with(|| {
+		let sender = ALICE;
+		let origin = RuntimeOrigin::signed(sender.clone());
+		let dao_id = setup_dao_with_governance::<Test>(sender.clone());
+		let metadata = b"http://my.cool.proposal".to_vec();
+		let hash = b"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".to_vec();
+
+		// nothing is created if the metadata is invalid
+		assert_noop!(
+			DaoVotes::create_proposal_with_metadata(
+				origin.clone(),
+				dao_id.clone(),
+				ProposalKind::Text,
+				metadata.clone(),
+				vec![0; 65]
+			),
+			DaoError::<Test>::HashInvalidWrongLength
+		);
+
+		assert_ok!(DaoVotes::create_proposal_with_metadata(
+			origin,
+			dao_id,
+			ProposalKind::Text,
+			metadata,
+			hash
+		));
+		let prop_id = DaoVotes::get_current_proposal_id();
+		assert!(!<ProposalSlots<Test>>::contains_key(prop_id));
+		let proposal = <Proposals<Test>>::get(prop_id).unwrap();
+		assert_eq!(proposal.creator, sender);
+		assert_eq!(proposal.status, ProposalStatus::Running);
+		assert_eq!(proposal.birth_block, 1);
+	});
+}
+
+#[test]
+fn expired_proposal_slots_are_refunded() {
+	new_test_ext().execute_with(|| {
+		let sender = ALICE;
+		let dao_id = setup_dao_with_governance::<Test>(sender.clone());
+		let asset_id = 1;
+		let reserved_currency = CurrencyOf::<Test>::reserved_balance(sender.clone());
+		let reserved_tokens = Assets::reserved(asset_id, sender.clone());
+
+		let slot_id = create_proposal_id::<Test>(sender.clone(), dao_id.clone());
+		let prop_id = setup_proposal::<Test>(sender.clone(), dao_id.clone());
+		assert_eq!(
+			CurrencyOf::<Test>::reserved_balance(sender.clone()),
+			reserved_currency + 2 * <Test as Config>::ProposalDeposit::get()
+		);
+
+		// slots are kept until they expire
+		let expiry = <Test as Config>::ProposalSlotExpiry::get() as u64;
+		run_to_block::<Test>(1 + expiry);
+		DaoVotes::on_idle(System::block_number(), Weight::MAX);
+		assert!(<ProposalSlots<Test>>::contains_key(slot_id));
+
+		// no weight, no work
+		run_to_block::<Test>(2 + expiry);
+		assert_eq!(DaoVotes::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+		assert!(<ProposalSlots<Test>>::contains_key(slot_id));
+
+		DaoVotes::on_idle(System::block_number(), Weight::MAX);
+		assert!(!<ProposalSlots<Test>>::contains_key(slot_id));
+		assert!(!<ProposalKinds<Test>>::contains_key(slot_id));
+		System::assert_last_event(Event::ProposalSlotExpired { proposal_id: slot_id }.into());
+		assert!(!<SlotExpiries<Test>>::contains_key(2 + expiry, slot_id));
+		// the next call continues after the processed blocks
+		assert_eq!(<NextSlotExpiry<Test>>::get(), 3 + expiry);
+
+		// the deposits of the expired slot have been refunded
+		assert_eq!(
+			CurrencyOf::<Test>::reserved_balance(sender.clone()),
+			reserved_currency + <Test as Config>::ProposalDeposit::get()
+		);
+		assert_eq!(Assets::reserved(asset_id, sender), reserved_tokens + 1);
+
+		// proposals with metadata are not affected
+		assert!(<Proposals<Test>>::contains_key(prop_id));
+		let bounded_dao_id: BoundedVec<_, _> = dao_id.try_into().unwrap();
+		assert!(!<DaoProposals<Test>>::contains_key(&bounded_dao_id, slot_id));
+		assert!(<DaoProposals<Test>>::contains_key(&bounded_dao_id, prop_id));
+	});
+}
+
 #[test]
 fn can_cast_and_remove_a_vote() {
 	new_test_ext().execute_with(|| {
@@ -125,6 +260,200 @@ fn can_cast_and_remove_a_vote() {
 	});
 }
 
+fn sign_vote(
+	pair: &sr25519::Pair,
+	proposal_id: u32,
+	in_favor: Option<bool>,
+	nonce: u32,
+) -> SignedVoteOf<Test> {
+	let payload = DaoVotes::signed_vote_payload(proposal_id, in_favor, nonce);
+	SignedVote {
+		proposal_id,
+		voter: pair.public().into(),
+		in_favor,
+		nonce,
+		signature: MultiSignature::from(pair.sign(&payload)),
+	}
+}
+
+#[test]
+fn can_submit_signed_votes() {
+	new_test_ext().execute_with(|| {
+		let sender = ALICE;
+		let dao_id = setup_dao_with_governance::<Test>(sender.clone());
+		let prop_id = setup_proposal::<Test>(sender.clone(), dao_id);
+
+		let pair = sr25519::Pair::from_seed(&[7u8; 32]);
+		let voter: AccountId = pair.public().into();
+		let asset_id = 1;
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(sender), asset_id, voter.clone(), 100));
+
+		// the relayer pays the fees, the voter signs the vote
+		let relayer = RuntimeOrigin::signed(BOB);
+		let votes: BoundedVec<_, _> =
+			vec![sign_vote(&pair, prop_id, Some(true), 0)].try_into().unwrap();
+		assert_ok!(DaoVotes::submit_signed_votes(relayer.clone(), votes.clone()));
+		assert_eq!(<Votes<Test>>::get(prop_id, voter.clone()), Some(true));
+		assert_eq!(<VoteNonces<Test>>::get(voter.clone()), 1);
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().in_favor, 100);
+
+		// a signed vote cannot be replayed
+		assert_noop!(
+			DaoVotes::submit_signed_votes(relayer.clone(), votes),
+			Error::<Test>::InvalidNonce
+		);
+
+		// the signature has to match the vote
+		let mut vote = sign_vote(&pair, prop_id, Some(true), 1);
+		vote.in_favor = Some(false);
+		assert_noop!(
+			DaoVotes::submit_signed_votes(relayer.clone(), vec![vote].try_into().unwrap()),
+			Error::<Test>::InvalidSignature
+		);
+
+		// the signature has to cover the domain of the vote
+		let payload = (prop_id, Some(true), 1_u32).encode();
+		vote.in_favor = Some(true);
+		vote.signature = MultiSignature::from(pair.sign(&payload));
+		assert_noop!(
+			DaoVotes::submit_signed_votes(relayer.clone(), vec![vote].try_into().unwrap()),
+			Error::<Test>::InvalidSignature
+		);
+
+		// a failing vote reverts the whole batch
+		let votes: BoundedVec<_, _> =
+			vec![sign_vote(&pair, prop_id, Some(false), 1), sign_vote(&pair, prop_id + 1, None, 2)]
+				.try_into()
+				.unwrap();
+		assert_noop!(
+			DaoVotes::submit_signed_votes(relayer.clone(), votes),
+			Error::<Test>::ProposalDoesNotExist
+		);
+
+		// votes can be changed with the next nonce
+		let votes: BoundedVec<_, _> =
+			vec![sign_vote(&pair, prop_id, Some(false), 1)].try_into().unwrap();
+		assert_ok!(DaoVotes::submit_signed_votes(relayer, votes));
+		let proposal = Proposals::<Test>::get(prop_id).unwrap();
+		assert_eq!(proposal.in_favor, 0);
+		assert_eq!(proposal.against, 100);
+	});
+}
+
+#[test]
+fn can_commit_and_reveal_votes() {
+	new_test_ext().execute_with(|| {
+		use commons::traits::pallets::ActiveProposals;
+
+		let sender = ALICE;
+		let origin = RuntimeOrigin::signed(sender.clone());
+		let dao_id = setup_dao::<Test>(sender.clone());
+		assert_ok!(DaoVotes::set_governance_majority_vote(origin.clone(), dao_id.clone(), 2, 1, 0));
+		let public_prop_id = setup_proposal::<Test>(sender.clone(), dao_id.clone());
+
+		let salt = [7u8; 32];
+		let commitment = DaoVotes::vote_commitment(public_prop_id, &sender, true, salt);
+		assert_noop!(
+			DaoVotes::commit_vote(origin.clone(), public_prop_id, commitment),
+			Error::<Test>::CommitRevealNotEnabled
+		);
+
+		// only the owner can enable commit-reveal mode
+		assert_noop!(
+			DaoVotes::set_commit_reveal(RuntimeOrigin::signed(BOB), dao_id.clone(), Some(3)),
+			DaoError::<Test>::DaoSignerNotOwner
+		);
+		// a reveal period needs at least one block
+		assert_noop!(
+			DaoVotes::set_commit_reveal(origin.clone(), dao_id.clone(), Some(0)),
+			Error::<Test>::RevealDurationZero
+		);
+		setup_commit_reveal::<Test>(sender.clone(), dao_id.clone(), 3);
+
+		// running proposals keep the mode they were created with
+		assert_ok!(DaoVotes::vote(origin.clone(), public_prop_id, Some(true)));
+		assert_noop!(
+			DaoVotes::commit_vote(origin.clone(), public_prop_id, commitment),
+			Error::<Test>::CommitRevealNotEnabled
+		);
+
+		// public votes are not accepted on proposals in commit-reveal mode
+		let prop_id = setup_proposal::<Test>(sender.clone(), dao_id.clone());
+		assert_noop!(
+			DaoVotes::vote(origin.clone(), prop_id, Some(true)),
+			Error::<Test>::VoteMustBeCommitted
+		);
+
+		// committed votes are not counted and cannot be revealed during the voting period
+		let commitment = DaoVotes::vote_commitment(prop_id, &sender, true, salt);
+		let reserved = CurrencyOf::<Test>::reserved_balance(sender.clone());
+		assert_ok!(DaoVotes::commit_vote(origin.clone(), prop_id, commitment));
+		assert_eq!(CurrencyOf::<Test>::reserved_balance(sender.clone()), reserved + 1);
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().in_favor, 0);
+		// a commitment is bound to its voter
+		assert_ok!(DaoVotes::commit_vote(RuntimeOrigin::signed(BOB), prop_id, commitment));
+		assert_noop!(
+			DaoVotes::reveal_vote(origin.clone(), prop_id, true, salt),
+			Error::<Test>::RevealPeriodNotStarted
+		);
+
+		run_to_block::<Test>(4);
+		assert_noop!(
+			DaoVotes::commit_vote(origin.clone(), prop_id, commitment),
+			Error::<Test>::ProposalDurationHasPassed
+		);
+		assert_noop!(
+			DaoVotes::finalize_proposal(origin.clone(), prop_id),
+			Error::<Test>::ProposalDurationHasNotPassed
+		);
+		// the proposal stays active during the reveal period
+		assert_eq!(DaoVotes::active_proposals_starting_time(dao_id.clone(), 6), vec![1]);
+
+		// the reveal has to match the commitment
+		assert_noop!(
+			DaoVotes::reveal_vote(origin.clone(), prop_id, false, salt),
+			Error::<Test>::VoteCommitmentMismatch
+		);
+		assert_noop!(
+			DaoVotes::reveal_vote(RuntimeOrigin::signed(BOB), prop_id, true, salt),
+			Error::<Test>::VoteCommitmentMismatch
+		);
+		assert_ok!(DaoVotes::reveal_vote(origin.clone(), prop_id, true, salt));
+		assert_eq!(<Votes<Test>>::get(prop_id, sender.clone()), Some(true));
+		assert_eq!(CurrencyOf::<Test>::reserved_balance(sender.clone()), reserved);
+		assert!(Proposals::<Test>::get(prop_id).unwrap().in_favor > 0);
+		assert_noop!(
+			DaoVotes::reveal_vote(origin.clone(), prop_id, true, salt),
+			Error::<Test>::VoteCommitmentDoesNotExist
+		);
+
+		// unrevealed commitments can only be removed after the reveal period
+		assert_noop!(
+			DaoVotes::remove_vote_commitment(RuntimeOrigin::signed(BOB), prop_id),
+			Error::<Test>::RevealPeriodNotOver
+		);
+
+		// the reveal period ends after `reveal_duration` blocks
+		run_to_block::<Test>(7);
+		assert_noop!(
+			DaoVotes::reveal_vote(RuntimeOrigin::signed(BOB), prop_id, true, salt),
+			Error::<Test>::RevealPeriodHasPassed
+		);
+		assert_eq!(DaoVotes::active_proposals_starting_time(dao_id, 7), vec![]);
+		assert_ok!(DaoVotes::finalize_proposal(origin.clone(), prop_id));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Accepted);
+
+		let reserved = CurrencyOf::<Test>::reserved_balance(BOB);
+		assert_ok!(DaoVotes::remove_vote_commitment(RuntimeOrigin::signed(BOB), prop_id));
+		assert_eq!(CurrencyOf::<Test>::reserved_balance(BOB), reserved - 1);
+		assert!(!<VoteCommitments<Test>>::contains_key(prop_id, BOB));
+		assert_noop!(
+			DaoVotes::remove_vote_commitment(origin, prop_id),
+			Error::<Test>::VoteCommitmentDoesNotExist
+		);
+	});
+}
+
 #[test]
 fn can_fault_a_proposal() {
 	new_test_ext().execute_with(|| {
@@ -283,6 +612,255 @@ fn voting_outcome_successful_proposal_and_mark_implemented() {
 	})
 }
 
+#[test]
+fn voting_outcome_optimistic_proposals() {
+	new_test_ext().execute_with(|| {
+		let sender = ALICE;
+		let origin = RuntimeOrigin::signed(sender.clone());
+		let dao_id = setup_dao::<Test>(sender.clone());
+
+		// a threshold above the whole supply could never be reached
+		assert_noop!(
+			DaoVotes::set_governance_optimistic_vote(origin.clone(), dao_id.clone(), 0, 1, 1025),
+			Error::<Test>::ObjectionThresholdTooHigh
+		);
+
+		// proposals are rejected once more than 256 / 1024 of the supply objects
+		assert_ok!(DaoVotes::set_governance_optimistic_vote(
+			origin.clone(),
+			dao_id.clone(),
+			0,
+			1,
+			256
+		));
+		let asset_id = 1;
+		assert_ok!(Assets::transfer(origin.clone(), asset_id, BOB, 300));
+
+		// a proposal without any votes passes
+		let prop_id = setup_proposal::<Test>(sender.clone(), dao_id.clone());
+		run_to_block::<Test>(2);
+		assert_ok!(DaoVotes::finalize_proposal(origin.clone(), prop_id));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Accepted);
+
+		// a proposal with enough objections is rejected, regardless of the votes in favour
+		let prop_id = setup_proposal::<Test>(sender, dao_id);
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id, Some(true)));
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(BOB), prop_id, Some(false)));
+		run_to_block::<Test>(3);
+		assert_ok!(DaoVotes::finalize_proposal(origin, prop_id));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Rejected);
+	})
+}
+
+#[test]
+fn accepted_proposals_change_the_governance_of_a_sub_dao() {
+	new_test_ext().execute_with(|| {
+		let sender = ALICE;
+		let origin = RuntimeOrigin::signed(sender.clone());
+		let dao_id = setup_dao::<Test>(sender.clone());
+		assert_ok!(DaoVotes::set_governance_optimistic_vote(
+			origin.clone(),
+			dao_id.clone(),
+			0,
+			1,
+			512
+		));
+		assert_ok!(DaoCore::create_sub_dao(
+			origin.clone(),
+			dao_id.clone(),
+			b"SDAO".to_vec(),
+			b"Sub DAO".to_vec()
+		));
+
+		let kind = |child: &[u8]| ProposalKind::ChildGovernanceChange {
+			child: child.to_vec().try_into().unwrap(),
+			proposal_duration: 10,
+			proposal_token_deposit: 1,
+			voting: Voting::Optimistic { objection_threshold_per_1024: 768 },
+		};
+
+		// only the sub-DAOs of a DAO can be targeted
+		assert_noop!(
+			DaoVotes::create_proposal_of_kind(origin.clone(), dao_id.clone(), kind(b"GDAO")),
+			Error::<Test>::NotASubDao
+		);
+
+		assert_ok!(DaoVotes::create_proposal_of_kind(origin.clone(), dao_id, kind(b"SDAO")));
+		let prop_id = DaoVotes::get_current_proposal_id();
+		setup_proposal_with_id::<Test>(sender, prop_id);
+		run_to_block::<Test>(2);
+		assert_ok!(DaoVotes::finalize_proposal(origin, prop_id));
+
+		let child: BoundedVec<_, _> = b"SDAO".to_vec().try_into().unwrap();
+		assert_eq!(
+			Governances::<Test>::get(&child),
+			Some(GovernanceOf::<Test> {
+				proposal_duration: 10,
+				proposal_token_deposit: 1,
+				voting: Voting::Optimistic { objection_threshold_per_1024: 768 },
+			})
+		);
+		System::assert_has_event(
+			Event::SubDaoGovernanceChanged { dao_id: child, proposal_id: prop_id }.into(),
+		);
+	})
+}
+
+#[test]
+fn membership_daos_vote_one_member_one_vote() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(ALICE);
+		let dao_id = b"MDAO".to_vec();
+		let charlie = AccountId::new([3u8; 32]);
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"Committee".to_vec()));
+		assert_ok!(DaoCore::enable_membership(
+			origin.clone(),
+			dao_id.clone(),
+			vec![ALICE, BOB].try_into().unwrap()
+		));
+		assert_ok!(DaoVotes::set_governance_majority_vote(
+			origin.clone(),
+			dao_id.clone(),
+			0,
+			0,
+			255
+		));
+
+		// members can only be changed in membership DAOs and only members can propose
+		let kind = ProposalKind::MembershipChange { member: charlie.clone(), add: true };
+		assert_noop!(
+			DaoVotes::create_proposal_of_kind(
+				RuntimeOrigin::signed(charlie.clone()),
+				dao_id.clone(),
+				kind.clone()
+			),
+			Error::<Test>::NotAMember
+		);
+		assert_ok!(DaoVotes::create_proposal_of_kind(origin.clone(), dao_id.clone(), kind));
+		let prop_id = DaoVotes::get_current_proposal_id();
+		setup_proposal_with_id::<Test>(ALICE, prop_id);
+
+		// every member has a single vote
+		assert_noop!(
+			DaoVotes::vote(RuntimeOrigin::signed(charlie.clone()), prop_id, Some(true)),
+			Error::<Test>::NotAMember
+		);
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id, Some(true)));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().in_favor, 1);
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(BOB), prop_id, Some(true)));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().in_favor, 2);
+
+		run_to_block::<Test>(2);
+		assert_ok!(DaoVotes::finalize_proposal(origin.clone(), prop_id));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Accepted);
+
+		// the accepted proposal added the member
+		let id: BoundedVec<_, _> = dao_id.clone().try_into().unwrap();
+		assert!(DaoCore::is_member(&id, &charlie));
+
+		// a change that can no longer be applied is reported
+		let kind = ProposalKind::MembershipChange { member: charlie, add: true };
+		assert_ok!(DaoVotes::create_proposal_of_kind(origin.clone(), dao_id, kind));
+		let prop_id = DaoVotes::get_current_proposal_id();
+		setup_proposal_with_id::<Test>(ALICE, prop_id);
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id, Some(true)));
+		run_to_block::<Test>(3);
+		assert_ok!(DaoVotes::finalize_proposal(origin, prop_id));
+		System::assert_has_event(
+			Event::MembershipChangeFailed {
+				proposal_id: prop_id,
+				error: DaoError::<Test>::AlreadyMember.into(),
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn destroying_a_dao_removes_its_proposals() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(ALICE);
+		let dao_id = b"MDAO".to_vec();
+		let reserved_currency = CurrencyOf::<Test>::reserved_balance(ALICE);
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"Committee".to_vec()));
+		assert_ok!(DaoCore::enable_membership(
+			origin.clone(),
+			dao_id.clone(),
+			vec![ALICE, BOB].try_into().unwrap()
+		));
+		assert_ok!(DaoVotes::set_governance_majority_vote(
+			origin.clone(),
+			dao_id.clone(),
+			10,
+			0,
+			255
+		));
+		let kind = ProposalKind::MembershipChange { member: BOB, add: false };
+		assert_ok!(DaoVotes::create_proposal_of_kind(origin.clone(), dao_id.clone(), kind));
+		let prop_id = DaoVotes::get_current_proposal_id();
+		setup_proposal_with_id::<Test>(ALICE, prop_id);
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(BOB), prop_id, Some(true)));
+
+		// the open proposal is removed together with the DAO
+		assert_ok!(DaoCore::destroy_dao(origin.clone(), dao_id.clone()));
+		let bounded_dao_id: BoundedVec<_, _> = dao_id.try_into().unwrap();
+		assert!(!<Governances<Test>>::contains_key(&bounded_dao_id));
+		assert!(!<Proposals<Test>>::contains_key(prop_id));
+		assert!(!<Votes<Test>>::contains_key(prop_id, BOB));
+		assert_eq!(<DaoProposals<Test>>::iter_key_prefix(&bounded_dao_id).count(), 0);
+		assert_eq!(CurrencyOf::<Test>::reserved_balance(ALICE), reserved_currency);
+
+		// and can no longer be finalized
+		run_to_block::<Test>(20);
+		assert_noop!(
+			DaoVotes::finalize_proposal(origin, prop_id),
+			Error::<Test>::ProposalDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn force_destroying_a_dao_removes_its_proposals() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(ALICE);
+		let dao_id = b"MDAO".to_vec();
+		let reserved_currency = CurrencyOf::<Test>::reserved_balance(ALICE);
+		assert_ok!(DaoCore::create_dao(origin.clone(), dao_id.clone(), b"Committee".to_vec()));
+		assert_ok!(DaoCore::enable_membership(
+			origin.clone(),
+			dao_id.clone(),
+			vec![ALICE, BOB].try_into().unwrap()
+		));
+		assert_ok!(DaoVotes::set_governance_majority_vote(
+			origin.clone(),
+			dao_id.clone(),
+			10,
+			0,
+			255
+		));
+		let kind = ProposalKind::MembershipChange { member: BOB, add: false };
+		assert_ok!(DaoVotes::create_proposal_of_kind(origin.clone(), dao_id.clone(), kind));
+		let slot_id = DaoVotes::get_current_proposal_id();
+		let prop_id = setup_proposal::<Test>(ALICE, dao_id.clone());
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id, Some(true)));
+
+		// the unused slot and the open proposal are removed together with the DAO
+		assert_ok!(DaoCore::force_destroy_dao(RuntimeOrigin::root(), dao_id.clone()));
+		let bounded_dao_id: BoundedVec<_, _> = dao_id.try_into().unwrap();
+		assert!(!<Governances<Test>>::contains_key(&bounded_dao_id));
+		assert!(!<ProposalSlots<Test>>::contains_key(slot_id));
+		assert!(!<Proposals<Test>>::contains_key(prop_id));
+		assert!(!<Votes<Test>>::contains_key(prop_id, ALICE));
+		assert_eq!(CurrencyOf::<Test>::reserved_balance(ALICE), reserved_currency);
+
+		run_to_block::<Test>(20);
+		assert_noop!(
+			DaoVotes::finalize_proposal(origin, prop_id),
+			Error::<Test>::ProposalDoesNotExist
+		);
+	})
+}
+
 #[test]
 fn returns_active_proposals_for_a_dao() {
 	new_test_ext().execute_with(|| {
@@ -376,4 +954,170 @@ fn on_vote_calculation_callback_works() {
 		// and this should be multiplied by 2, as defined in the above ink! contract
 		assert_eq!(<Proposals<Test>>::get(prop_id).unwrap().in_favor, 100);
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn dissolving_a_dao_removes_its_proposals() {
+	new_test_ext().execute_with(|| {
+		let sender = ALICE;
+		let origin = RuntimeOrigin::signed(sender.clone());
+		let dao_id = setup_dao_with_governance::<Test>(sender.clone());
+		let reserved_currency = CurrencyOf::<Test>::reserved_balance(sender.clone());
+
+		let slot_id = create_proposal_id::<Test>(sender.clone(), dao_id.clone());
+		let prop_id = setup_proposal::<Test>(sender.clone(), dao_id.clone());
+		assert_ok!(DaoVotes::vote(origin.clone(), prop_id, Some(true)));
+		let bounded_dao_id: BoundedVec<_, _> = dao_id.clone().try_into().unwrap();
+		assert!(<DaoProposals<Test>>::contains_key(&bounded_dao_id, slot_id));
+		assert!(<DaoProposals<Test>>::contains_key(&bounded_dao_id, prop_id));
+
+		assert_ok!(DaoCore::dissolve_dao(origin.clone(), dao_id.clone()));
+		assert_noop!(
+			DaoVotes::create_proposal(origin.clone(), dao_id.clone()),
+			DaoError::<Test>::DaoIsDissolving
+		);
+
+		// the token is destroyed before the proposals are removed
+		DaoCore::on_idle(System::block_number(), Weight::MAX);
+		assert!(<Proposals<Test>>::contains_key(prop_id));
+
+		for _ in 0..10 {
+			DaoCore::on_idle(System::block_number(), Weight::MAX);
+		}
+		assert!(!<Governances<Test>>::contains_key(&bounded_dao_id));
+		assert_eq!(<DaoProposals<Test>>::iter_key_prefix(&bounded_dao_id).count(), 0);
+		assert!(!<ProposalSlots<Test>>::contains_key(slot_id));
+		assert!(!<ProposalKinds<Test>>::contains_key(prop_id));
+		assert!(!<Proposals<Test>>::contains_key(prop_id));
+		assert!(!<Votes<Test>>::contains_key(prop_id, sender.clone()));
+		assert_noop!(
+			DaoVotes::create_proposal(origin, dao_id),
+			DaoError::<Test>::DaoDoesNotExist
+		);
+
+		// all deposits have been released
+		assert_eq!(
+			CurrencyOf::<Test>::reserved_balance(sender),
+			reserved_currency - <Test as pallet_dao_core::Config>::DaoDeposit::get()
+		);
+	});
+}
+
+#[test]
+fn migrates_proposal_slots_to_v1() {
+	new_test_ext().execute_with(|| {
+		let dao_id: DaoIdOf<Test> = b"DAO".to_vec().try_into().unwrap();
+		// governances keep their encoding, `Custom` stays at its index
+		assert_eq!(Voting::Custom.encode(), vec![1]);
+		let governance = GovernanceOf::<Test> {
+			proposal_duration: 10,
+			proposal_token_deposit: 5,
+			voting: Voting::Custom,
+		};
+		Governances::<Test>::insert(&dao_id, governance);
+		let slot = OldProposalSlot { dao_id: dao_id.clone(), creator: ALICE };
+		unhashed::put(&ProposalSlots::<Test>::hashed_key_for(1u32), &slot);
+		let proposal = ProposalOf::<Test> {
+			dao_id: dao_id.clone(),
+			creator: BOB,
+			birth_block: 1,
+			meta: Default::default(),
+			meta_hash: Default::default(),
+			status: ProposalStatus::Running,
+			in_favor: 0,
+			against: 0,
+		};
+		Proposals::<Test>::insert(2u32, proposal.clone());
+		Proposals::<Test>::insert(3u32, proposal);
+		StorageVersion::new(0).put::<DaoVotes>();
+		System::set_block_number(42);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(DaoVotes::on_chain_storage_version(), 1);
+		let governance = Governances::<Test>::get(&dao_id).unwrap();
+		assert_eq!(governance.voting, Voting::Custom);
+		assert_eq!(governance.proposal_token_deposit, 5);
+		let slot = ProposalSlots::<Test>::get(1u32).unwrap();
+		assert_eq!(slot.creator, ALICE);
+		assert_eq!(slot.created_at, 42);
+		let expiry = <Test as Config>::ProposalSlotExpiry::get() as u64;
+		assert!(<SlotExpiries<Test>>::contains_key(43 + expiry, 1u32));
+		assert_eq!(<NextSlotExpiry<Test>>::get(), 42);
+		// the reserved DAO tokens are unknown
+		assert_eq!(slot.token_deposit, 0);
+		// slots are indexed by their DAO, proposals one after the other
+		assert!(<DaoProposals<Test>>::contains_key(&dao_id, 1u32));
+		assert_eq!(<DaoProposals<Test>>::iter_key_prefix(&dao_id).count(), 2);
+		assert!(<ProposalIndexCursor<Test>>::exists());
+		assert!(!DaoVotes::cleanup_dao(&dao_id, 100));
+
+		DaoVotes::on_idle(42, Weight::MAX);
+		assert!(<DaoProposals<Test>>::contains_key(&dao_id, 2u32));
+		assert!(<DaoProposals<Test>>::contains_key(&dao_id, 3u32));
+		assert!(!<ProposalIndexCursor<Test>>::exists());
+	});
+}
+
+#[test]
+fn can_query_proposals_and_dry_run_their_outcome() {
+	new_test_ext().execute_with(|| {
+		let sender = ALICE;
+		let dao_id = setup_dao_with_governance::<Test>(sender.clone());
+		assert_eq!(DaoVotes::dao_with_governance(b"NONE".to_vec()), None);
+		let (dao, governance) = DaoVotes::dao_with_governance(dao_id.clone()).unwrap();
+		assert_eq!(dao.owner, sender);
+		assert_eq!(governance.unwrap().proposal_duration, 1000);
+
+		let prop_id = setup_proposal::<Test>(sender.clone(), dao_id.clone());
+		let active = DaoVotes::active_proposals(dao_id.clone());
+		assert_eq!(active.len(), 1);
+		assert_eq!(active[0].0, prop_id);
+		let proposal_dao = DaoVotes::proposal_dao(prop_id).map(|dao_id| dao_id.to_vec());
+		assert_eq!(proposal_dao, Some(dao_id.clone()));
+		assert_eq!(DaoVotes::proposal_dao(prop_id + 1), None);
+
+		// the voting power is what a vote counts with
+		assert_eq!(DaoVotes::voting_power(prop_id, BOB), Some(0));
+		let voting_power = DaoVotes::voting_power(prop_id, sender.clone()).unwrap();
+		assert_ok!(DaoVotes::vote(RuntimeOrigin::signed(sender), prop_id, Some(true)));
+		let (_, proposal) = DaoVotes::active_proposals(dao_id).pop().unwrap();
+		assert_eq!(proposal.in_favor, voting_power);
+
+		// the outcome is evaluated like `finalize_proposal` does, without finalizing
+		assert_eq!(
+			DaoVotes::proposal_outcome(prop_id),
+			Err(Error::<Test>::ProposalDurationHasNotPassed.into())
+		);
+		run_to_block::<Test>(System::block_number() + 1001);
+		assert_eq!(DaoVotes::proposal_outcome(prop_id).map(|(_, accepted)| accepted), Ok(true));
+		assert_eq!(Proposals::<Test>::get(prop_id).unwrap().status, ProposalStatus::Running);
+	})
+}
+
+#[test]
+fn can_explain_the_voting_power() {
+	new_test_ext().execute_with(|| {
+		let sender = ALICE;
+		let dao_id = setup_dao_with_governance::<Test>(sender.clone());
+		let asset_id = 1;
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(sender.clone()), asset_id, BOB, 50));
+		assert_ok!(Assets::delegate(RuntimeOrigin::signed(BOB), asset_id, sender.clone()));
+
+		let prop_id = setup_proposal::<Test>(sender.clone(), dao_id);
+		assert_eq!(DaoVotes::explain_voting_power(prop_id + 1, sender.clone()), None);
+
+		// without a DAO contract the voting power is the own and delegated balance
+		let explanation = DaoVotes::explain_voting_power(prop_id, sender.clone()).unwrap();
+		assert_eq!(explanation.delegated, 50);
+		assert_eq!(explanation.plugins, vec![]);
+		assert_eq!(explanation.balance + explanation.delegated, explanation.voting_power);
+		assert_eq!(Some(explanation.voting_power), DaoVotes::voting_power(prop_id, sender));
+
+		let explanation = DaoVotes::explain_voting_power(prop_id, BOB).unwrap();
+		assert_eq!(
+			(explanation.balance, explanation.delegated, explanation.voting_power),
+			(0, 0, 0)
+		);
+	})
+}
@@ -8,8 +8,14 @@ use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet_dao_core::BalanceOf;
 use pallet_dao_core::DaoIdOf;
 use scale_info::TypeInfo;
+use sp_std::prelude::*;
 
-pub type ProposalSlotOf<T> = ProposalSlot<DaoIdOf<T>, <T as frame_system::Config>::AccountId>;
+pub type ProposalSlotOf<T> = ProposalSlot<
+	DaoIdOf<T>,
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	BalanceOf<T>,
+>;
 pub type ProposalOf<T> = Proposal<
 	DaoIdOf<T>,
 	<T as frame_system::Config>::AccountId,
@@ -20,10 +26,28 @@ pub type ProposalOf<T> = Proposal<
 
 pub type GovernanceOf<T> = Governance<BalanceOf<T>>;
 
+pub type ProposalKindOf<T> = ProposalKind<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	BoundedVec<u8, <T as crate::Config>::MaxCallDataLength>,
+	DaoIdOf<T>,
+>;
+
+pub type VotingPowerExplanationOf<T> =
+	VotingPowerExplanation<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+pub type SignedVoteOf<T> = SignedVote<
+	<T as crate::Config>::ProposalId,
+	<T as frame_system::Config>::AccountId,
+	<T as crate::Config>::OffchainSignature,
+>;
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub struct ProposalSlot<DaoId, AccountId> {
+pub struct ProposalSlot<DaoId, AccountId, BlockId, Balance> {
 	pub dao_id: DaoId,
 	pub creator: AccountId,
+	pub created_at: BlockId,
+	pub token_deposit: Balance,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -38,6 +62,65 @@ pub struct Proposal<DaoId, AccountId, BlockId, Balance, Metadata> {
 	pub against: Balance,
 }
 
+/// The typed payload of a proposal.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum ProposalKind<AccountId, Balance, CallData, DaoId> {
+	// a proposal that is fully described by its metadata
+	Text,
+	// transfer `amount` from the DAO treasury to `beneficiary`
+	TreasurySpend {
+		beneficiary: AccountId,
+		amount: Balance,
+	},
+	// replace the governance of the DAO
+	GovernanceChange {
+		proposal_duration: u32,
+		proposal_token_deposit: Balance,
+		voting: Voting,
+	},
+	// call the message `selector` of `contract` with the SCALE encoded arguments `data`
+	ContractCall {
+		contract: AccountId,
+		selector: [u8; 4],
+		data: CallData,
+	},
+	// add or remove `member` from the DAO
+	MembershipChange {
+		member: AccountId,
+		add: bool,
+	},
+	// replace the governance of the sub-DAO `child`, applied once the proposal is accepted
+	ChildGovernanceChange {
+		child: DaoId,
+		proposal_duration: u32,
+		proposal_token_deposit: Balance,
+		voting: Voting,
+	},
+}
+
+/// A vote signed off-chain by `voter`, see `Pallet::signed_vote_payload` for the signed payload.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SignedVote<ProposalId, AccountId, Signature> {
+	pub proposal_id: ProposalId,
+	pub voter: AccountId,
+	pub in_favor: Option<bool>,
+	pub nonce: u32,
+	pub signature: Signature,
+}
+
+/// How the voting power of an account on a proposal comes about.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct VotingPowerExplanation<AccountId, Balance> {
+	// the own token balance at the birth block of the proposal
+	pub balance: Balance,
+	// the amount delegated to the account at the birth block of the proposal
+	pub delegated: Balance,
+	// the voting power after each vote plugin of the DAO contract, in the order they are applied
+	pub plugins: Vec<(AccountId, Balance)>,
+	// the voting power a vote is counted with
+	pub voting_power: Balance,
+}
+
 #[derive(
 	Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo,
 )]
@@ -71,4 +154,11 @@ pub enum Voting {
 	},
 	// hook point entrypoint
 	Custom,
+	// Proposals pass unless objected to
+	Optimistic {
+		// how many nays of the token supply are required to reject a proposal
+		// thus proposal rejection requires: nays > token_supply / 1024 *
+		// objection_threshold_per_1024
+		objection_threshold_per_1024: u16,
+	},
 }
@@ -42,6 +42,15 @@ pub trait WeightInfo {
 	fn vote() -> Weight;
 	fn set_governance_majority_vote() -> Weight;
 	fn mark_implemented() -> Weight;
+	fn submit_signed_votes(n: u32, ) -> Weight;
+	fn set_commit_reveal() -> Weight;
+	fn commit_vote() -> Weight;
+	fn reveal_vote() -> Weight;
+	fn set_governance_optimistic_vote() -> Weight;
+	fn create_proposal_of_kind() -> Weight;
+	fn create_proposal_with_metadata() -> Weight;
+	fn expire_proposal_slot() -> Weight;
+	fn remove_vote_commitment() -> Weight;
 }
 
 /// Weights for pallet_dao_votes using the Substrate node and recommended hardware.
@@ -59,6 +68,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Votes::CurrentProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Votes::ProposalSlots` (r:0 w:1)
 	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::SlotExpiries` (r:0 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::DaoProposals` (r:0 w:1)
+	/// Proof: `Votes::DaoProposals` (`max_values`: None, `max_size`: Some(32), added: 2527, mode: `MaxEncodedLen`)
 	fn create_proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `549`
@@ -66,20 +79,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 43_961_000 picoseconds.
 		Weight::from_parts(45_236_000, 4014)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Votes::ProposalSlots` (r:1 w:1)
 	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Votes::Proposals` (r:0 w:1)
 	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::RevealDurations` (r:1 w:0)
+	/// Proof: `Votes::RevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:0 w:1)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalKinds` (r:1 w:0)
+	/// Proof: `Votes::ProposalKinds` (`max_values`: None, `max_size`: Some(1123), added: 3598, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::SlotExpiries` (r:0 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalTokenDeposits` (r:0 w:1)
+	/// Proof: `Votes::ProposalTokenDeposits` (`max_values`: None, `max_size`: Some(28), added: 2523, mode: `MaxEncodedLen`)
 	fn set_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `120`
 		//  Estimated: `3585`
 		// Minimum execution time: 14_465_000 picoseconds.
 		Weight::from_parts(15_090_000, 3585)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Votes::Proposals` (r:1 w:1)
 	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -158,6 +187,223 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Votes::VoteNonces` (r:1 w:1)
+	/// Proof: `Votes::VoteNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Proposals` (r:1 w:1)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::Votes` (r:1 w:1)
+	/// Proof: `Votes::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::AccountHistory` (r:1 w:0)
+	/// Proof: `Assets::AccountHistory` (`max_values`: None, `max_size`: Some(14400072), added: 14402547, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn submit_signed_votes(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `657 + n * (64 ±0)`
+		//  Estimated: `14403537 + n * (2527 ±0)`
+		// Minimum execution time: 87_514_000 picoseconds.
+		Weight::from_parts(12_408_000, 14403537)
+			// Standard Error: 31_206
+			.saturating_add(Weight::from_parts(79_862_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::RevealDurations` (r:0 w:1)
+	/// Proof: `Votes::RevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_commit_reveal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 12_411_000 picoseconds.
+		Weight::from_parts(13_107_000, 3893)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Votes::Proposals` (r:1 w:0)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:1 w:0)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::VoteCommitments` (r:0 w:1)
+	/// Proof: `Votes::VoteCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn commit_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `488`
+		//  Estimated: `3953`
+		// Minimum execution time: 17_902_000 picoseconds.
+		Weight::from_parts(18_736_000, 3953)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Votes::Proposals` (r:1 w:1)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:1 w:0)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::VoteCommitments` (r:1 w:1)
+	/// Proof: `Votes::VoteCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Votes` (r:1 w:1)
+	/// Proof: `Votes::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::AccountHistory` (r:1 w:0)
+	/// Proof: `Assets::AccountHistory` (`max_values`: None, `max_size`: Some(14400072), added: 14402547, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reveal_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `753`
+		//  Estimated: `14403537`
+		// Minimum execution time: 41_519_000 picoseconds.
+		Weight::from_parts(43_078_000, 14403537)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:0 w:1)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_governance_optimistic_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 12_802_000 picoseconds.
+		Weight::from_parts(13_691_000, 3893)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::CurrentProposalId` (r:1 w:1)
+	/// Proof: `Votes::CurrentProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalKinds` (r:0 w:1)
+	/// Proof: `Votes::ProposalKinds` (`max_values`: None, `max_size`: Some(1123), added: 3598, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalSlots` (r:0 w:1)
+	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::SlotExpiries` (r:0 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::DaoProposals` (r:0 w:1)
+	/// Proof: `Votes::DaoProposals` (`max_values`: None, `max_size`: Some(32), added: 2527, mode: `MaxEncodedLen`)
+	fn create_proposal_of_kind() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `549`
+		//  Estimated: `4588`
+		// Minimum execution time: 49_117_000 picoseconds.
+		Weight::from_parts(51_390_000, 4588)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::CurrentProposalId` (r:1 w:1)
+	/// Proof: `Votes::CurrentProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalSlots` (r:1 w:1)
+	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalKinds` (r:0 w:1)
+	/// Proof: `Votes::ProposalKinds` (`max_values`: None, `max_size`: Some(1123), added: 3598, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Proposals` (r:0 w:1)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::RevealDurations` (r:1 w:0)
+	/// Proof: `Votes::RevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:0 w:1)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::SlotExpiries` (r:0 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::DaoProposals` (r:0 w:1)
+	/// Proof: `Votes::DaoProposals` (`max_values`: None, `max_size`: Some(32), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalTokenDeposits` (r:0 w:1)
+	/// Proof: `Votes::ProposalTokenDeposits` (`max_values`: None, `max_size`: Some(28), added: 2523, mode: `MaxEncodedLen`)
+	fn create_proposal_with_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `549`
+		//  Estimated: `4588`
+		// Minimum execution time: 58_930_000 picoseconds.
+		Weight::from_parts(61_244_000, 4588)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Votes::ProposalSlots` (r:1 w:1)
+	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalKinds` (r:0 w:1)
+	/// Proof: `Votes::ProposalKinds` (`max_values`: None, `max_size`: Some(1123), added: 3598, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::SlotExpiries` (r:1 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::DaoProposals` (r:0 w:1)
+	/// Proof: `Votes::DaoProposals` (`max_values`: None, `max_size`: Some(32), added: 2527, mode: `MaxEncodedLen`)
+	fn expire_proposal_slot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `4588`
+		// Minimum execution time: 38_175_000 picoseconds.
+		Weight::from_parts(39_802_000, 4588)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Votes::VoteCommitments` (r:1 w:1)
+	/// Proof: `Votes::VoteCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Proposals` (r:1 w:0)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:1 w:0)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_vote_commitment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `4077`
+		// Minimum execution time: 24_318_000 picoseconds.
+		Weight::from_parts(25_102_000, 4077)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -174,6 +420,10 @@ impl WeightInfo for () {
 	/// Proof: `Votes::CurrentProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Votes::ProposalSlots` (r:0 w:1)
 	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::SlotExpiries` (r:0 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::DaoProposals` (r:0 w:1)
+	/// Proof: `Votes::DaoProposals` (`max_values`: None, `max_size`: Some(32), added: 2527, mode: `MaxEncodedLen`)
 	fn create_proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `549`
@@ -181,20 +431,36 @@ impl WeightInfo for () {
 		// Minimum execution time: 43_961_000 picoseconds.
 		Weight::from_parts(45_236_000, 4014)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Votes::ProposalSlots` (r:1 w:1)
 	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Votes::Proposals` (r:0 w:1)
 	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::RevealDurations` (r:1 w:0)
+	/// Proof: `Votes::RevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:0 w:1)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalKinds` (r:1 w:0)
+	/// Proof: `Votes::ProposalKinds` (`max_values`: None, `max_size`: Some(1123), added: 3598, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::SlotExpiries` (r:0 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalTokenDeposits` (r:0 w:1)
+	/// Proof: `Votes::ProposalTokenDeposits` (`max_values`: None, `max_size`: Some(28), added: 2523, mode: `MaxEncodedLen`)
 	fn set_metadata() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `120`
 		//  Estimated: `3585`
 		// Minimum execution time: 14_465_000 picoseconds.
 		Weight::from_parts(15_090_000, 3585)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Votes::Proposals` (r:1 w:1)
 	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -273,4 +539,221 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Votes::VoteNonces` (r:1 w:1)
+	/// Proof: `Votes::VoteNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Proposals` (r:1 w:1)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::Votes` (r:1 w:1)
+	/// Proof: `Votes::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::AccountHistory` (r:1 w:0)
+	/// Proof: `Assets::AccountHistory` (`max_values`: None, `max_size`: Some(14400072), added: 14402547, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn submit_signed_votes(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `657 + n * (64 ±0)`
+		//  Estimated: `14403537 + n * (2527 ±0)`
+		// Minimum execution time: 87_514_000 picoseconds.
+		Weight::from_parts(12_408_000, 14403537)
+			// Standard Error: 31_206
+			.saturating_add(Weight::from_parts(79_862_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::RevealDurations` (r:0 w:1)
+	/// Proof: `Votes::RevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_commit_reveal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 12_411_000 picoseconds.
+		Weight::from_parts(13_107_000, 3893)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Votes::Proposals` (r:1 w:0)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:1 w:0)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::VoteCommitments` (r:0 w:1)
+	/// Proof: `Votes::VoteCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn commit_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `488`
+		//  Estimated: `3953`
+		// Minimum execution time: 17_902_000 picoseconds.
+		Weight::from_parts(18_736_000, 3953)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Votes::Proposals` (r:1 w:1)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:1 w:0)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::VoteCommitments` (r:1 w:1)
+	/// Proof: `Votes::VoteCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Votes` (r:1 w:1)
+	/// Proof: `Votes::Votes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::AccountHistory` (r:1 w:0)
+	/// Proof: `Assets::AccountHistory` (`max_values`: None, `max_size`: Some(14400072), added: 14402547, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reveal_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `753`
+		//  Estimated: `14403537`
+		// Minimum execution time: 41_519_000 picoseconds.
+		Weight::from_parts(43_078_000, 14403537)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:0 w:1)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_governance_optimistic_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3893`
+		// Minimum execution time: 12_802_000 picoseconds.
+		Weight::from_parts(13_691_000, 3893)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::CurrentProposalId` (r:1 w:1)
+	/// Proof: `Votes::CurrentProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalKinds` (r:0 w:1)
+	/// Proof: `Votes::ProposalKinds` (`max_values`: None, `max_size`: Some(1123), added: 3598, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalSlots` (r:0 w:1)
+	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::SlotExpiries` (r:0 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::DaoProposals` (r:0 w:1)
+	/// Proof: `Votes::DaoProposals` (`max_values`: None, `max_size`: Some(32), added: 2527, mode: `MaxEncodedLen`)
+	fn create_proposal_of_kind() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `549`
+		//  Estimated: `4588`
+		// Minimum execution time: 49_117_000 picoseconds.
+		Weight::from_parts(51_390_000, 4588)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Hookpoints::SpecificCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::SpecificCallbacks` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Hookpoints::GlobalCallbacks` (r:1 w:0)
+	/// Proof: `Hookpoints::GlobalCallbacks` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::CurrentProposalId` (r:1 w:1)
+	/// Proof: `Votes::CurrentProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalSlots` (r:1 w:1)
+	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalKinds` (r:0 w:1)
+	/// Proof: `Votes::ProposalKinds` (`max_values`: None, `max_size`: Some(1123), added: 3598, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Proposals` (r:0 w:1)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::RevealDurations` (r:1 w:0)
+	/// Proof: `Votes::RevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:0 w:1)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::SlotExpiries` (r:0 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::DaoProposals` (r:0 w:1)
+	/// Proof: `Votes::DaoProposals` (`max_values`: None, `max_size`: Some(32), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalTokenDeposits` (r:0 w:1)
+	/// Proof: `Votes::ProposalTokenDeposits` (`max_values`: None, `max_size`: Some(28), added: 2523, mode: `MaxEncodedLen`)
+	fn create_proposal_with_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `549`
+		//  Estimated: `4588`
+		// Minimum execution time: 58_930_000 picoseconds.
+		Weight::from_parts(61_244_000, 4588)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Votes::ProposalSlots` (r:1 w:1)
+	/// Proof: `Votes::ProposalSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DaoCore::Daos` (r:1 w:0)
+	/// Proof: `DaoCore::Daos` (`max_values`: None, `max_size`: Some(428), added: 2903, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::ProposalKinds` (r:0 w:1)
+	/// Proof: `Votes::ProposalKinds` (`max_values`: None, `max_size`: Some(1123), added: 3598, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::SlotExpiries` (r:1 w:1)
+	/// Proof: `Votes::SlotExpiries` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::DaoProposals` (r:0 w:1)
+	/// Proof: `Votes::DaoProposals` (`max_values`: None, `max_size`: Some(32), added: 2527, mode: `MaxEncodedLen`)
+	fn expire_proposal_slot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `4588`
+		// Minimum execution time: 38_175_000 picoseconds.
+		Weight::from_parts(39_802_000, 4588)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Votes::VoteCommitments` (r:1 w:1)
+	/// Proof: `Votes::VoteCommitments` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Votes::Proposals` (r:1 w:0)
+	/// Proof: `Votes::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::Governances` (r:1 w:0)
+	/// Proof: `Votes::Governances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Votes::ProposalRevealDurations` (r:1 w:0)
+	/// Proof: `Votes::ProposalRevealDurations` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_vote_commitment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `4077`
+		// Minimum execution time: 24_318_000 picoseconds.
+		Weight::from_parts(25_102_000, 4077)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
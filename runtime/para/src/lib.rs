@@ -116,6 +116,13 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Storage migrations of the DAO pallets, each a no-op once its pallet is up to date.
+pub type Migrations = (
+	pallet_dao_assets::migrations::v1::MigrateToV1<Runtime>,
+	pallet_dao_core::migrations::v2::MigrateToV2<Runtime>,
+	pallet_dao_votes::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -123,6 +130,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 type EventRecord = frame_system::EventRecord<
@@ -185,10 +193,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("template-parachain"),
 	impl_name: create_runtime_str!("template-parachain"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -564,21 +572,22 @@ impl pallet_contracts::Config for Runtime {
 parameter_types! {
 	pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
 	pub const AssetsStringLimit: u32 = 50;
+	pub const DaoCorePalletId: PalletId = PalletId(*b"gdao/dao");
 }
 
 // DAO assets
 impl pallet_dao_assets::Config for Runtime {
 	type ActiveProposals = Votes;
+	type AssetDaos = DaoCore;
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
-	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
-	type Currency = Balances;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type ApprovalDeposit = ApprovalDeposit;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type StringLimit = AssetsStringLimit;
+	type MaxDelegation = ConstU32<100>;
 	type HistoryHorizon = ConstU32<{ 5 * 144000 }>; // a day is 14400 blocks of 6s
 	type WeightInfo = pallet_dao_assets::weights::SubstrateWeight<Runtime>;
 
@@ -595,9 +604,25 @@ impl pallet_dao_core::Config for Runtime {
 	type MaxLengthMetadata = ConstU32<256>;
 	type Currency = Balances;
 	type DaoDeposit = ConstU128<{ 10 * DOT }>;
+	type DaoDepositPerByte = ConstU128<{ 60 * MILLIDOT }>;
 	type TokenUnits = ConstU8<10>;
+	type MaxTokenUnits = ConstU8<18>;
+	type MaxMetadataHistory = ConstU32<32>;
+	type MaxAttachedDaos = ConstU32<32>;
+	type MaxMembers = ConstU32<100>;
+	type RequireDaoIdCommitment = ConstBool<false>;
+	type DaoIdCommitmentDelay = ConstU32<10>;
+	type DaoIdCommitmentExpiry = ConstU32<{ 7 * DAYS }>;
 	type AssetId = u32;
-	type WeightInfo = pallet_dao_core::weights::SubstrateWeight<Runtime>;
+	type ExposeAsset = Assets;
+	type CoreWeightInfo = pallet_dao_core::weights::SubstrateWeight<Runtime>;
+	type MetadataValidator = pallet_dao_core::hooks::OnSetMetadata<Runtime>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type OwnershipTransferExpiry = ConstU32<{ 7 * DAYS }>;
+	type DaoCleanup = Votes;
+	type DissolveItemsLimit = ConstU32<100>;
+	type PalletId = DaoCorePalletId;
+	type MaxAllocations = ConstU32<100>;
 }
 
 // DAO votes
@@ -605,6 +630,13 @@ impl pallet_dao_votes::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ProposalDeposit = ConstU128<{ 10 * DOT }>;
 	type ProposalId = u64;
+	type MaxProposals = ConstU32<25>;
+	type ProposalSlotExpiry = ConstU32<{ 7 * DAYS }>;
+	type MaxCallDataLength = ConstU32<1024>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type MaxSignedVotes = ConstU32<50>;
+	type VoteCommitmentDeposit = ConstU128<{ DOT }>;
 	type WeightInfo = pallet_dao_votes::weights::SubstrateWeight<Runtime>;
 }
 
@@ -813,6 +845,68 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_dao_votes_runtime_api::DaoApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		<Runtime as pallet_dao_votes::Config>::ProposalId,
+		pallet_dao_core::DaoOf<Runtime>,
+		pallet_dao_votes::GovernanceOf<Runtime>,
+		pallet_dao_votes::ProposalOf<Runtime>,
+		pallet_dao_votes::VotingPowerExplanationOf<Runtime>,
+	> for Runtime
+	{
+		fn get_dao(
+			dao_id: Vec<u8>,
+		) -> Option<(
+			pallet_dao_core::DaoOf<Runtime>,
+			Option<pallet_dao_votes::GovernanceOf<Runtime>>,
+		)> {
+			Votes::dao_with_governance(dao_id)
+		}
+		fn get_proposal_dao(
+			proposal_id: <Runtime as pallet_dao_votes::Config>::ProposalId,
+		) -> Option<Vec<u8>> {
+			Votes::proposal_dao(proposal_id).map(|dao_id| dao_id.to_vec())
+		}
+		fn get_active_proposals(
+			dao_id: Vec<u8>,
+		) -> Vec<(
+			<Runtime as pallet_dao_votes::Config>::ProposalId,
+			pallet_dao_votes::ProposalOf<Runtime>,
+		)> {
+			Votes::active_proposals(dao_id)
+		}
+		fn get_voting_power(
+			proposal_id: <Runtime as pallet_dao_votes::Config>::ProposalId,
+			voter: AccountId,
+		) -> Option<Balance> {
+			Votes::voting_power(proposal_id, voter)
+		}
+		fn explain_voting_power(
+			proposal_id: <Runtime as pallet_dao_votes::Config>::ProposalId,
+			voter: AccountId,
+		) -> Option<pallet_dao_votes::VotingPowerExplanationOf<Runtime>> {
+			Votes::explain_voting_power(proposal_id, voter)
+		}
+		fn dry_run_finalize(
+			proposal_id: <Runtime as pallet_dao_votes::Config>::ProposalId,
+		) -> Result<bool, sp_runtime::DispatchError> {
+			Votes::proposal_outcome(proposal_id).map(|(_, accepted)| accepted)
+		}
+		fn get_historical_balance(
+			dao_id: Vec<u8>,
+			who: AccountId,
+			block: BlockNumber,
+		) -> Option<Balance> {
+			Votes::historical_balance(dao_id, who, block)
+		}
+		fn get_historical_supply(dao_id: Vec<u8>, block: BlockNumber) -> Option<Balance> {
+			Votes::historical_supply(dao_id, block)
+		}
+	}
+
 	impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord>
 		for Runtime
 	{
@@ -42,7 +42,7 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 
 pub use frame_system::Call as SystemCall;
@@ -136,12 +136,12 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
-	//   the compatible custom types.
-	spec_version: 100,
+	// This value is kept at 100 or above to notify Polkadot-JS App (https://polkadot.js.org/apps)
+	//   to use the compatible custom types.
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -378,11 +378,13 @@ impl pallet_utility::Config for Runtime {
 parameter_types! {
 	pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
 	pub const AssetsStringLimit: u32 = 50;
+	pub const DaoCorePalletId: PalletId = PalletId(*b"gdao/dao");
 }
 
 // DAO assets
 impl pallet_dao_assets::Config for Runtime {
 	type ActiveProposals = Votes;
+	type AssetDaos = DaoCore;
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
 	type AssetIdParameter = u32;
@@ -408,10 +410,25 @@ impl pallet_dao_core::Config for Runtime {
 	type MaxLengthMetadata = ConstU32<256>;
 	type Currency = Balances;
 	type DaoDeposit = ConstU128<{ 10 * DOT }>;
+	type DaoDepositPerByte = ConstU128<{ 60 * MILLIDOT }>;
 	type TokenUnits = ConstU8<10>;
+	type MaxTokenUnits = ConstU8<18>;
+	type MaxMetadataHistory = ConstU32<32>;
+	type MaxAttachedDaos = ConstU32<32>;
+	type MaxMembers = ConstU32<100>;
+	type RequireDaoIdCommitment = ConstBool<false>;
+	type DaoIdCommitmentDelay = ConstU32<10>;
+	type DaoIdCommitmentExpiry = ConstU32<{ 7 * DAYS }>;
 	type AssetId = u32;
 	type ExposeAsset = Assets;
 	type CoreWeightInfo = pallet_dao_core::weights::SubstrateWeight<Runtime>;
+	type MetadataValidator = pallet_dao_core::hooks::OnSetMetadata<Runtime>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type OwnershipTransferExpiry = ConstU32<{ 7 * DAYS }>;
+	type DaoCleanup = Votes;
+	type DissolveItemsLimit = ConstU32<100>;
+	type PalletId = DaoCorePalletId;
+	type MaxAllocations = ConstU32<100>;
 }
 
 // DAO votes
@@ -420,6 +437,12 @@ impl pallet_dao_votes::Config for Runtime {
 	type ProposalDeposit = ConstU128<{ 10 * DOT }>;
 	type ProposalId = u64;
 	type MaxProposals = ConstU32<25>;
+	type ProposalSlotExpiry = ConstU32<{ 7 * DAYS }>;
+	type MaxCallDataLength = ConstU32<1024>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type MaxSignedVotes = ConstU32<50>;
+	type VoteCommitmentDeposit = ConstU128<{ DOT }>;
 	type WeightInfo = pallet_dao_votes::weights::SubstrateWeight<Runtime>;
 }
 
@@ -474,6 +497,13 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations of the DAO pallets, each a no-op once its pallet is up to date.
+pub type Migrations = (
+	pallet_dao_assets::migrations::v1::MigrateToV1<Runtime>,
+	pallet_dao_core::migrations::v2::MigrateToV2<Runtime>,
+	pallet_dao_votes::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -481,6 +511,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 type EventRecord = frame_system::EventRecord<
@@ -674,6 +705,132 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_dao_core_runtime_api::DaoCoreApi<
+		Block,
+		AccountId,
+		pallet_dao_core::AssetIdOf<Runtime>,
+		pallet_dao_core::DaoOf<Runtime>,
+	> for Runtime
+	{
+		fn get_dao(dao_id: Vec<u8>) -> Option<pallet_dao_core::DaoOf<Runtime>> {
+			let dao_id: pallet_dao_core::DaoIdOf<Runtime> = dao_id.try_into().ok()?;
+			DaoCore::get_dao(dao_id)
+		}
+		fn get_daos(start: Option<Vec<u8>>, limit: u32) -> Vec<pallet_dao_core::DaoOf<Runtime>> {
+			DaoCore::daos(start, limit)
+		}
+		fn get_daos_by_owner(
+			owner: AccountId,
+			start: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<pallet_dao_core::DaoOf<Runtime>> {
+			DaoCore::daos_by_owner(owner, start, limit)
+		}
+		fn get_asset_daos(asset_id: pallet_dao_core::AssetIdOf<Runtime>) -> Vec<Vec<u8>> {
+			DaoCore::asset_daos(&asset_id)
+		}
+	}
+
+	impl pallet_dao_votes_runtime_api::DaoApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		<Runtime as pallet_dao_votes::Config>::ProposalId,
+		pallet_dao_core::DaoOf<Runtime>,
+		pallet_dao_votes::GovernanceOf<Runtime>,
+		pallet_dao_votes::ProposalOf<Runtime>,
+		pallet_dao_votes::VotingPowerExplanationOf<Runtime>,
+	> for Runtime
+	{
+		fn get_dao(
+			dao_id: Vec<u8>,
+		) -> Option<(
+			pallet_dao_core::DaoOf<Runtime>,
+			Option<pallet_dao_votes::GovernanceOf<Runtime>>,
+		)> {
+			Votes::dao_with_governance(dao_id)
+		}
+		fn get_proposal_dao(
+			proposal_id: <Runtime as pallet_dao_votes::Config>::ProposalId,
+		) -> Option<Vec<u8>> {
+			Votes::proposal_dao(proposal_id).map(|dao_id| dao_id.to_vec())
+		}
+		fn get_active_proposals(
+			dao_id: Vec<u8>,
+		) -> Vec<(
+			<Runtime as pallet_dao_votes::Config>::ProposalId,
+			pallet_dao_votes::ProposalOf<Runtime>,
+		)> {
+			Votes::active_proposals(dao_id)
+		}
+		fn get_voting_power(
+			proposal_id: <Runtime as pallet_dao_votes::Config>::ProposalId,
+			voter: AccountId,
+		) -> Option<Balance> {
+			Votes::voting_power(proposal_id, voter)
+		}
+		fn explain_voting_power(
+			proposal_id: <Runtime as pallet_dao_votes::Config>::ProposalId,
+			voter: AccountId,
+		) -> Option<pallet_dao_votes::VotingPowerExplanationOf<Runtime>> {
+			Votes::explain_voting_power(proposal_id, voter)
+		}
+		fn dry_run_finalize(
+			proposal_id: <Runtime as pallet_dao_votes::Config>::ProposalId,
+		) -> Result<bool, sp_runtime::DispatchError> {
+			Votes::proposal_outcome(proposal_id).map(|(_, accepted)| accepted)
+		}
+		fn get_historical_balance(
+			dao_id: Vec<u8>,
+			who: AccountId,
+			block: BlockNumber,
+		) -> Option<Balance> {
+			Votes::historical_balance(dao_id, who, block)
+		}
+		fn get_historical_supply(dao_id: Vec<u8>, block: BlockNumber) -> Option<Balance> {
+			Votes::historical_supply(dao_id, block)
+		}
+	}
+
+	impl pallet_dao_assets_runtime_api::AssetsHistoryApi<
+		Block,
+		pallet_dao_core::AssetIdOf<Runtime>,
+		AccountId,
+		Balance,
+		BlockNumber,
+	> for Runtime
+	{
+		fn total_historical_balance(
+			asset_id: pallet_dao_core::AssetIdOf<Runtime>,
+			who: AccountId,
+			block: BlockNumber,
+		) -> (BlockNumber, Balance) {
+			Assets::total_historical_balance(asset_id, who, block)
+		}
+		fn total_historical_supply(
+			asset_id: pallet_dao_core::AssetIdOf<Runtime>,
+			block: BlockNumber,
+		) -> Option<Balance> {
+			Assets::total_historical_supply(asset_id, block)
+		}
+		fn get_checkpoint_blocks(
+			asset_id: pallet_dao_core::AssetIdOf<Runtime>,
+			who: AccountId,
+		) -> Vec<BlockNumber> {
+			let (mut blocks, _) = Assets::get_checkpoint_blocks(&asset_id, &who);
+			blocks.sort();
+			blocks
+		}
+		fn get_delegations(
+			asset_id: pallet_dao_core::AssetIdOf<Runtime>,
+			who: AccountId,
+			block: BlockNumber,
+		) -> (Balance, Vec<(AccountId, Balance)>) {
+			Assets::historical_delegations(asset_id, who, block)
+		}
+	}
+
 	impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord>
 		for Runtime
 	{